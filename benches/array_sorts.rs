@@ -0,0 +1,304 @@
+// Copyright 2024-2026 Johanna Sörngård
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Runtime benchmarks of the sorting functions.
+//!
+//! Run with `cargo bench`. Every benchmark prints the median time of a number of runs.
+
+// The benchmarks are only run on recent compilers, not on the MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    rngs::SmallRng,
+    Rng, SeedableRng,
+};
+
+use compile_time_sort::{
+    into_sorted_char_array, into_sorted_i128_array, into_sorted_i32_array,
+    into_sorted_i32_array_radix, into_sorted_i32_array_seeded, into_sorted_i32_array_shell,
+    into_sorted_i64_array, sort_char_slice, sort_i32_slice,
+};
+
+const RUNS: usize = 101;
+
+/// Runs the given function `RUNS` times on a copy of the input and prints the median running time.
+fn bench<T: Copy, R>(name: &str, input: T, mut f: impl FnMut(T) -> R) {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let input = black_box(input);
+            let start = Instant::now();
+            black_box(f(input));
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    println!("{name:<60} {:>12.3?}", times[RUNS / 2]);
+}
+
+/// Sorts the given array with insertion sort in the same way as the crate does for small arrays.
+// The elements are swapped through a temporary like in the crate, since `swap` is not `const` on its MSRV.
+#[allow(clippy::manual_swap)]
+fn insertion_sort<T: PartialOrd + Copy, const N: usize>(mut array: [T; N]) -> [T; N] {
+    for i in 1..N {
+        let mut j = i;
+        while j > 0 && array[j - 1] > array[j] {
+            let temp = array[j - 1];
+            array[j - 1] = array[j];
+            array[j] = temp;
+            j -= 1;
+        }
+    }
+    array
+}
+
+/// Benchmarks sorting `CROSSOVER_ARRAYS` random arrays of length `N` with insertion sort and with the given
+/// crate function, in order to find the length where introsort starts to be faster than insertion sort.
+///
+/// The crate function uses insertion sort itself for arrays up to `INSERTION_SIZE` elements,
+/// so the times only differ above that length.
+fn bench_insertion_crossover<T, const N: usize>(
+    rng: &mut SmallRng,
+    sort_name: &str,
+    sort: fn([T; N]) -> [T; N],
+) where
+    T: PartialOrd + Copy + std::fmt::Debug,
+    Standard: Distribution<T>,
+{
+    let arrays: [[T; N]; CROSSOVER_ARRAYS] =
+        core::array::from_fn(|_| core::array::from_fn(|_| rng.gen()));
+    let type_name = std::any::type_name::<T>();
+    bench(
+        &format!("insertion sort, {type_name}, N = {N}, {CROSSOVER_ARRAYS} times"),
+        arrays,
+        |arrays| arrays.map(insertion_sort),
+    );
+    bench(
+        &format!("{sort_name}, N = {N}, {CROSSOVER_ARRAYS} times"),
+        arrays,
+        |arrays| arrays.map(sort),
+    );
+}
+
+/// Calls [`bench_insertion_crossover`] with every given array length.
+macro_rules! bench_insertion_crossover {
+    ($rng:ident, $sort:ident; $($n:literal),+) => {
+        $(
+            bench_insertion_crossover::<_, $n>(&mut $rng, stringify!($sort), $sort);
+        )+
+    };
+}
+
+/// The number of arrays sorted in every benchmark of the insertion sort crossover.
+const CROSSOVER_ARRAYS: usize = 1000;
+
+/// The partition that the array introsort used before it was made branchless.
+/// It is kept here to compare against the branchless partition.
+#[allow(clippy::manual_swap)]
+fn branchy_partition<const N: usize>(
+    mut arr: [i32; N],
+    left: usize,
+    right: usize,
+) -> (usize, [i32; N]) {
+    let pivot_index = left + (right - left) / 2;
+    let last_index = right - 1;
+
+    let temp = arr[pivot_index];
+    arr[pivot_index] = arr[last_index];
+    arr[last_index] = temp;
+
+    let mut store_index = left;
+    let mut i = left;
+    while i < last_index {
+        if arr[i] < arr[last_index] {
+            let temp = arr[i];
+            arr[i] = arr[store_index];
+            arr[store_index] = temp;
+            store_index += 1;
+        }
+        i += 1;
+    }
+    let temp = arr[store_index];
+    arr[store_index] = arr[last_index];
+    arr[last_index] = temp;
+
+    (store_index, arr)
+}
+
+/// A copy of the branchless partition that the array introsort uses.
+#[allow(clippy::manual_swap)]
+fn branchless_partition<const N: usize>(
+    mut arr: [i32; N],
+    left: usize,
+    right: usize,
+) -> (usize, [i32; N]) {
+    let pivot_index = left + (right - left) / 2;
+    let last_index = right - 1;
+
+    let temp = arr[pivot_index];
+    arr[pivot_index] = arr[last_index];
+    arr[last_index] = temp;
+
+    let pivot = arr[last_index];
+    let mut store_index = left;
+    let mut i = left;
+    while i < last_index {
+        let is_less = arr[i] < pivot;
+        let temp = arr[i];
+        arr[i] = arr[store_index];
+        arr[store_index] = temp;
+        store_index += is_less as usize;
+        i += 1;
+    }
+    let temp = arr[store_index];
+    arr[store_index] = arr[last_index];
+    arr[last_index] = temp;
+
+    (store_index, arr)
+}
+
+/// The type of the partition functions above.
+type Partition<const N: usize> = fn([i32; N], usize, usize) -> (usize, [i32; N]);
+
+/// Sorts `array[left..right]` in the same way as the array introsort of the crate, but with the given partition.
+/// This makes it possible to measure the difference the partition makes on its own.
+fn introsort_with<const N: usize>(
+    mut array: [i32; N],
+    left: usize,
+    right: usize,
+    partition: Partition<N>,
+) -> [i32; N] {
+    if right - left <= 16 {
+        array[left..right].sort_unstable();
+        array
+    } else {
+        let (pivot_index, array) = partition(array, left, right);
+        let array = introsort_with(array, left, pivot_index, partition);
+        introsort_with(array, pivot_index + 1, right, partition)
+    }
+}
+
+fn main() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+
+    let mut random_i32 = [0_i32; 10_000];
+    rng.fill(&mut random_i32[..]);
+    bench(
+        "into_sorted_i32_array, random, N = 10000",
+        random_i32,
+        into_sorted_i32_array,
+    );
+
+    bench(
+        "introsort with the branchy partition, random, N = 10000",
+        random_i32,
+        |array| introsort_with(array, 0, array.len(), branchy_partition),
+    );
+    bench(
+        "introsort with the branchless partition, random, N = 10000",
+        random_i32,
+        |array| introsort_with(array, 0, array.len(), branchless_partition),
+    );
+
+    bench(
+        "sort_i32_slice, random, N = 10000",
+        random_i32,
+        |mut array| {
+            sort_i32_slice(&mut array);
+            array
+        },
+    );
+
+    let mut sorted_i32 = random_i32;
+    sorted_i32.sort();
+    bench(
+        "into_sorted_i32_array, sorted, N = 10000",
+        sorted_i32,
+        into_sorted_i32_array,
+    );
+
+    let mut reverse_sorted_i32 = sorted_i32;
+    reverse_sorted_i32.reverse();
+    bench(
+        "into_sorted_i32_array, reverse sorted, N = 10000",
+        reverse_sorted_i32,
+        into_sorted_i32_array,
+    );
+
+    let mut pairs_i32 = [[0_i32; 2]; 10_000];
+    for pair in pairs_i32.iter_mut() {
+        rng.fill(pair);
+    }
+    bench(
+        "into_sorted_i32_array, N = 2, 10000 times",
+        pairs_i32,
+        |pairs| pairs.map(into_sorted_i32_array),
+    );
+
+    let mut nearly_reverse_sorted_i32 = reverse_sorted_i32;
+    nearly_reverse_sorted_i32.swap(0, 10_000 - 1);
+    bench(
+        "into_sorted_i32_array, nearly reverse sorted, N = 10000",
+        nearly_reverse_sorted_i32,
+        into_sorted_i32_array,
+    );
+
+    let mut random_i32_500 = [0_i32; 500];
+    rng.fill(&mut random_i32_500[..]);
+    bench(
+        "into_sorted_i32_array, random, N = 500",
+        random_i32_500,
+        into_sorted_i32_array,
+    );
+    bench(
+        "into_sorted_i32_array_shell, random, N = 500",
+        random_i32_500,
+        into_sorted_i32_array_shell,
+    );
+
+    let random_char: [char; 10_000] = core::array::from_fn(|_| rng.gen());
+    bench(
+        "into_sorted_char_array, random, N = 10000",
+        random_char,
+        into_sorted_char_array,
+    );
+    bench(
+        "sort_char_slice, random, N = 10000",
+        random_char,
+        |mut chars| {
+            sort_char_slice(&mut chars);
+            chars
+        },
+    );
+
+    let mut random_i32_1000 = [0_i32; 1000];
+    rng.fill(&mut random_i32_1000[..]);
+    bench(
+        "into_sorted_i32_array, random, N = 1000",
+        random_i32_1000,
+        into_sorted_i32_array,
+    );
+    bench(
+        "into_sorted_i32_array_radix, random, N = 1000",
+        random_i32_1000,
+        into_sorted_i32_array_radix,
+    );
+
+    bench(
+        "into_sorted_i32_array_seeded, sorted, N = 10000",
+        sorted_i32,
+        |array| into_sorted_i32_array_seeded(array, 42),
+    );
+
+    // On an x86_64 machine the two sorts take the same time up to `INSERTION_SIZE` (16) elements, as expected.
+    // Above that plain insertion sort stays 10-45% faster than introsort up to at least 40 elements
+    // for all three element widths, so the measurements do not show that wider types want a smaller cutover.
+    // They would allow a larger one at runtime, but `const` evaluation also has to be considered,
+    // where the O(N^2) steps of insertion sort are comparatively more expensive.
+    bench_insertion_crossover!(rng, into_sorted_i32_array; 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40);
+    bench_insertion_crossover!(rng, into_sorted_i64_array; 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40);
+    bench_insertion_crossover!(rng, into_sorted_i128_array; 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40);
+}
@@ -12,6 +12,8 @@
 //! # Features
 //!
 //! `nested`: enables the functions that sort slices of slices and arrays of slices.
+//!
+//! `macros`: enables the `sorted_array!` macro that sorts an array at compile time.
 
 // This crate is implemented mainly through macros. This is used to copy-paste the implementation
 // of the sorting algorithms many times, once for each type, as we can not use const generics due to MSRV.
@@ -34,11 +36,39 @@
 #![allow(clippy::incompatible_msrv)]
 
 use core::cmp::Ordering;
-use core::num::NonZeroUsize;
+use core::num::{NonZeroU8, NonZeroUsize, Wrapping};
 
 /// If the array/slice is smaller than this size insertion sort will be used.
 const INSERTION_SIZE: usize = 16;
 
+/// The gap sequence found empirically by Marcin Ciura, used by the shellsort functions in decreasing order.
+/// Arrays longer than the largest gap use additional gaps that grow by a factor of 9/4.
+const CIURA_GAPS: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+
+/// The error type returned by the fallible (`try_*`) functions in this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortError {
+    /// The given index is not a valid index into the array.
+    IndexOutOfRange,
+    /// The array is longer than [`MAX_CONST_SORT_LEN`].
+    TooLargeForConst,
+}
+
+impl core::fmt::Display for SortError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IndexOutOfRange => f.write_str("the index is out of range of the array"),
+            Self::TooLargeForConst => {
+                f.write_str("the array is too long to be sorted during `const` evaluation")
+            }
+        }
+    }
+}
+
+#[rustversion::since(1.81.0)]
+impl core::error::Error for SortError {}
+
 // region: comparison wrappers
 
 /// This macro generates wrappers around the default comparison operators for the given types.
@@ -238,10 +268,6 @@ const fn greater_than_str(a: &str, b: &str) -> bool {
     matches!(compare_str_slices(a, b), Ordering::Greater)
 }
 
-const fn less_or_equal_str(a: &str, b: &str) -> bool {
-    matches!(compare_str_slices(a, b), Ordering::Less | Ordering::Equal)
-}
-
 const fn less_than_str(a: &str, b: &str) -> bool {
     matches!(compare_str_slices(a, b), Ordering::Less)
 }
@@ -253,49 +279,53 @@ const fn less_than_str(a: &str, b: &str) -> bool {
 #[rustversion::since(1.83.0)]
 /// Defines a `const` function with the given name that takes in a mutable reference to a slice of the given type
 /// and sorts it using the introsort algorithm while switching to the insertion sort algorithm when the array is small.
+///
+/// After partitioning, the function only recurses into the smaller partition and loops on the larger one.
+/// This bounds the recursion depth to O(log N) regardless of how unbalanced the partitions are.
 macro_rules! const_slice_introsort {
-    ($tpe:ty, $intro_name:ident, $insertion_name:ident, $heap_name:ident, $max_heapify_name: ident, $less_or_equal:ident, $greater_than:ident) => {
+    ($tpe:ty, $intro_name:ident, $insertion_name:ident, $heap_name:ident, $max_heapify_name: ident, $less_than:ident, $greater_than:ident) => {
         const_slice_insertion_sort!($tpe, $insertion_name, $greater_than);
 
         const_slice_heapsort!($tpe, $heap_name, $max_heapify_name, $greater_than);
 
-        const fn $intro_name(slice: &mut [$tpe], recursion_depth: u32) {
-            if slice.len() <= 1 {
-            } else if slice.len() <= INSERTION_SIZE {
-                $insertion_name(slice);
-            } else if recursion_depth == 0 {
-                $heap_name(slice);
-            } else {
-                let (pivot, rest) = slice
-                    .split_first_mut()
-                    .expect("slice is not empty, as verified above");
-
-                let mut left = 0;
-                let mut right = rest.len() - 1;
-                while left <= right {
-                    if $less_or_equal(rest[left], *pivot) {
-                        left += 1;
-                    } else if $greater_than(rest[right], *pivot) {
-                        if right == 0 {
-                            break;
-                        }
-                        right -= 1;
-                    } else {
-                        (rest[left], rest[right]) = (rest[right], rest[left]);
-                        left += 1;
-                        if right == 0 {
-                            break;
-                        }
-                        right -= 1;
-                    }
+        const fn $intro_name(mut slice: &mut [$tpe], mut recursion_depth: u32) {
+            loop {
+                if slice.len() <= 1 {
+                    return;
+                } else if slice.len() <= INSERTION_SIZE {
+                    $insertion_name(slice);
+                    return;
+                } else if recursion_depth == 0 {
+                    $heap_name(slice);
+                    return;
                 }
 
+                // The first element is the pivot. Like in the array introsort the swap is done unconditionally
+                // and the comparison only decides whether the store index advances, which avoids a hard to predict branch.
+                let pivot = slice[0];
+                let mut store_index = 1;
+                let mut i = 1;
+                while i < slice.len() {
+                    let is_less = $less_than(slice[i], pivot);
+                    (slice[i], slice[store_index]) = (slice[store_index], slice[i]);
+                    store_index += is_less as usize;
+                    i += 1;
+                }
+                let left = store_index - 1;
                 (slice[0], slice[left]) = (slice[left], slice[0]);
 
-                let (left, right) = slice.split_at_mut(left);
-                $intro_name(left, recursion_depth - 1);
-                if let Some((_pivot, right)) = right.split_first_mut() {
-                    $intro_name(right, recursion_depth - 1);
+                recursion_depth -= 1;
+                let whole = slice;
+                let (left, right) = whole.split_at_mut(left);
+                let (_pivot, right) = right
+                    .split_first_mut()
+                    .expect("the pivot is in the right half");
+                if left.len() < right.len() {
+                    $intro_name(left, recursion_depth);
+                    slice = right;
+                } else {
+                    $intro_name(right, recursion_depth);
+                    slice = left;
                 }
             }
         }
@@ -320,9 +350,9 @@ macro_rules! const_array_introsort {
             if len <= 1 {
                 array
             } else if len <= INSERTION_SIZE {
-                $insertion_name(array)
+                $insertion_name(array, left, right)
             } else if recursion_depth == 0 {
-                $heap_name(array)
+                $heap_name(array, left, right)
             } else {
                 let (pivot_index, mut array) = $partition_name(array, left, right);
                 array = $intro_name(array, recursion_depth - 1, left, pivot_index);
@@ -344,15 +374,18 @@ macro_rules! const_array_introsort {
             arr[pivot_index] = arr[last_index];
             arr[last_index] = temp;
 
+            // The swap is done unconditionally and the comparison only decides whether the store index advances.
+            // This is correct since `arr[store_index..i]` only contains elements that are not less than the pivot,
+            // and it avoids a hard to predict branch when the function is called at runtime.
+            let pivot = arr[last_index];
             let mut store_index = left;
             let mut i = left;
             while i < last_index {
-                if $less_than(arr[i], arr[last_index]) {
-                    let temp = arr[i];
-                    arr[i] = arr[store_index];
-                    arr[store_index] = temp;
-                    store_index += 1;
-                }
+                let is_less = $less_than(arr[i], pivot);
+                let temp = arr[i];
+                arr[i] = arr[store_index];
+                arr[store_index] = temp;
+                store_index += is_less as usize;
                 i += 1;
             }
             let temp = arr[store_index];
@@ -364,18 +397,23 @@ macro_rules! const_array_introsort {
     };
 }
 
-/// Defines a `const` function with the given name that sorts an array of the given type with the insertion sort algorithm.
+/// Defines a `const` function with the given name that sorts the elements in the range `left..right`
+/// of an array of the given type with the insertion sort algorithm.
 macro_rules! const_array_insertion_sort {
     ($tpe:ty, $name:ident, $greater_than:ident) => {
-        const fn $name<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
-            if N <= 1 {
+        const fn $name<const N: usize>(
+            mut array: [$tpe; N],
+            left: usize,
+            right: usize,
+        ) -> [$tpe; N] {
+            if right - left <= 1 {
                 return array;
             }
 
-            let mut i = 1;
-            while i < N {
+            let mut i = left + 1;
+            while i < right {
                 let mut j = i;
-                while j > 0 && $greater_than(array[j - 1], array[j]) {
+                while j > left && $greater_than(array[j - 1], array[j]) {
                     let temp = array[j - 1];
                     array[j - 1] = array[j];
                     array[j] = temp;
@@ -412,11 +450,15 @@ macro_rules! const_slice_insertion_sort {
     };
 }
 
-/// Defines a `const` function with the given name that sorts the given array with heapsort.
+/// Defines a `const` function with the given name that sorts the elements in the range `left..right`
+/// of an array of the given type with heapsort. The elements outside of the range are left untouched.
+///
+/// The heap is stored in `array[left..right]`, so the indices used by the heapify function are relative to `left`.
 macro_rules! const_array_heapsort {
     ($tpe:ty, $name:ident, $heapify_name:ident, $greater_than:ident) => {
         const fn $heapify_name<const N: usize>(
             mut array: [$tpe; N],
+            left: usize,
             n: usize,
             i: usize,
         ) -> [$tpe; N] {
@@ -425,46 +467,51 @@ macro_rules! const_array_heapsort {
             let l = 2 * i + 1;
             let r = l + 1;
 
-            if l < n && $greater_than(array[l], array[largest]) {
+            if l < n && $greater_than(array[left + l], array[left + largest]) {
                 largest = l;
             }
 
-            if r < n && $greater_than(array[r], array[largest]) {
+            if r < n && $greater_than(array[left + r], array[left + largest]) {
                 largest = r;
             }
 
             if largest != i {
-                let temp = array[i];
-                array[i] = array[largest];
-                array[largest] = temp;
+                let temp = array[left + i];
+                array[left + i] = array[left + largest];
+                array[left + largest] = temp;
 
-                array = $heapify_name(array, n, largest);
+                array = $heapify_name(array, left, n, largest);
             }
 
             array
         }
 
-        const fn $name<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
-            if N <= 1 {
+        const fn $name<const N: usize>(
+            mut array: [$tpe; N],
+            left: usize,
+            right: usize,
+        ) -> [$tpe; N] {
+            let n = right - left;
+            if n <= 1 {
                 return array;
             }
 
-            let mut i = N / 2 - 1;
+            let mut i = n / 2 - 1;
             while i > 0 {
-                array = $heapify_name(array, N, i);
+                array = $heapify_name(array, left, n, i);
                 i -= 1;
             }
             // This call is ok since we know `i` is never negative.
-            // We know this because we return early when `N` < 2, which means `i` >= 0.
-            array = $heapify_name(array, N, i);
+            // We know this because we return early when `n` < 2, which means `i` >= 0.
+            array = $heapify_name(array, left, n, i);
 
-            let mut i = N - 1;
+            let mut i = n - 1;
             while i > 0 {
-                let temp = array[0];
-                array[0] = array[i];
-                array[i] = temp;
+                let temp = array[left];
+                array[left] = array[left + i];
+                array[left + i] = temp;
 
-                array = $heapify_name(array, i, 0);
+                array = $heapify_name(array, left, i, 0);
                 i -= 1;
             }
 
@@ -534,7 +581,7 @@ macro_rules! impl_const_introsort {
         $(
             paste::paste! {
                 #[rustversion::since(1.83.0)]
-                const_slice_introsort!{&[$tpe], [<introsort_ $tpe _slice_slice>], [<insertion_sort_ $tpe _slice_slice>], [<heapsort_ $tpe _slice_slice>], [<max_heapify_ $tpe _slice_slice>], [<less_or_equal_ $tpe _slice>], [<greater_than_ $tpe _slice>]}
+                const_slice_introsort!{&[$tpe], [<introsort_ $tpe _slice_slice>], [<insertion_sort_ $tpe _slice_slice>], [<heapsort_ $tpe _slice_slice>], [<max_heapify_ $tpe _slice_slice>], [<less_than_ $tpe _slice>], [<greater_than_ $tpe _slice>]}
 
                 const_array_introsort!{&[$tpe], [<introsort_ $tpe _slice_array>], [<partition_ $tpe _slice_array>], [<insertion_sort_ $tpe _slice_array>], [<heapsort_ $tpe _slice_array>], [<max_heapify_ $tpe _slice_array>], [<greater_than_ $tpe _slice>], [<less_than_ $tpe _slice>]}
 
@@ -571,12 +618,15 @@ macro_rules! impl_const_introsort {
         $(
             paste::paste! {
                 #[rustversion::since(1.83.0)]
-                const_slice_introsort!{$tpe, [<introsort_ $tpe _slice>], [<insertion_sort_ $tpe _slice>], [<heapsort_ $tpe _slice>], [<max_heapify_ $tpe _slice>], [<less_or_equal_ $tpe>], [<greater_than_ $tpe>]}
+                const_slice_introsort!{$tpe, [<introsort_ $tpe _slice>], [<insertion_sort_ $tpe _slice>], [<heapsort_ $tpe _slice>], [<max_heapify_ $tpe _slice>], [<less_than_ $tpe>], [<greater_than_ $tpe>]}
 
                 const_array_introsort!{$tpe, [<introsort_ $tpe _array>], [<partition_ $tpe _array>], [<insertion_sort_ $tpe _array>], [<heapsort_ $tpe _array>], [<max_heapify_ $tpe _array>], [<greater_than_ $tpe>], [<less_than_ $tpe>]}
 
                 #[doc = "Sorts the given array of `" $tpe "`s using the introsort algorithm and returns it."]
                 #[doc = ""]
+                #[doc = "If the array is already sorted in ascending or descending order this is detected"]
+                #[doc = "in a single pass over it, and it is returned as is or reversed in O(N) time."]
+                #[doc = ""]
                 #[doc = "# Example"]
                 #[doc = ""]
                 #[doc = "```"]
@@ -586,12 +636,46 @@ macro_rules! impl_const_introsort {
                 #[doc = ""]
                 #[doc = "assert!(SORTED_ARRAY.is_sorted());"]
                 #[doc = "```"]
-                pub const fn [<into_sorted_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                pub const fn [<into_sorted_ $tpe _array>]<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
                     match NonZeroUsize::new(N) {
                         Some(nz) => {
                             if nz.get() == 1 {
                                 return array;
+                            } else if nz.get() == 2 {
+                                if [<greater_than_ $tpe>](array[0], array[1]) {
+                                    let temp = array[0];
+                                    array[0] = array[1];
+                                    array[1] = temp;
+                                }
+                                return array;
                             }
+
+                            // This check is only done once here at the top, and stops
+                            // as soon as the array is found to be neither ascending nor descending.
+                            let mut ascending = true;
+                            let mut descending = true;
+                            let mut i = 1;
+                            while i < N && (ascending || descending) {
+                                if [<greater_than_ $tpe>](array[i - 1], array[i]) {
+                                    ascending = false;
+                                } else if [<less_than_ $tpe>](array[i - 1], array[i]) {
+                                    descending = false;
+                                }
+                                i += 1;
+                            }
+                            if ascending {
+                                return array;
+                            } else if descending {
+                                let mut i = 0;
+                                while i < N / 2 {
+                                    let temp = array[i];
+                                    array[i] = array[N - 1 - i];
+                                    array[N - 1 - i] = temp;
+                                    i += 1;
+                                }
+                                return array;
+                            }
+
                             let max_depth = 2*ilog2(nz);
                             [<introsort_ $tpe _array>](array, max_depth, 0, N)
                         }
@@ -602,6 +686,9 @@ macro_rules! impl_const_introsort {
                 #[rustversion::since(1.83.0)]
                 #[doc = "Sorts the given slice of `" $tpe "`s using the introsort algorithm."]
                 #[doc = ""]
+                #[doc = "If the slice is already sorted in ascending or descending order this is detected"]
+                #[doc = "in a single pass over it, and it is left as is or reversed in O(N) time."]
+                #[doc = ""]
                 #[doc = "# Example"]
                 #[doc = ""]
                 #[doc = "```"]
@@ -619,6 +706,36 @@ macro_rules! impl_const_introsort {
                     if let Some(nz) = NonZeroUsize::new(slice.len()) {
                         if nz.get() <= 1 {
                             return;
+                        } else if nz.get() == 2 {
+                            if [<greater_than_ $tpe>](slice[0], slice[1]) {
+                                (slice[0], slice[1]) = (slice[1], slice[0]);
+                            }
+                            return;
+                        }
+
+                        // This check is only done once here at the top, like in the function that sorts arrays.
+                        // Equal neighbors do not rule out either order, so weakly descending slices are also reversed.
+                        let n = nz.get();
+                        let mut ascending = true;
+                        let mut descending = true;
+                        let mut i = 1;
+                        while i < n && (ascending || descending) {
+                            if [<greater_than_ $tpe>](slice[i - 1], slice[i]) {
+                                ascending = false;
+                            } else if [<less_than_ $tpe>](slice[i - 1], slice[i]) {
+                                descending = false;
+                            }
+                            i += 1;
+                        }
+                        if ascending {
+                            return;
+                        } else if descending {
+                            let mut i = 0;
+                            while i < n / 2 {
+                                (slice[i], slice[n - 1 - i]) = (slice[n - 1 - i], slice[i]);
+                                i += 1;
+                            }
+                            return;
                         }
 
                         let max_depth = 2*ilog2(nz);
@@ -680,7 +797,7 @@ impl_const_introsort! {
 }
 
 #[rustversion::since(1.83.0)]
-const_slice_introsort! {&str, introsort_str_slice, insertion_sort_str_slice, heapsort_str_slice, max_heapify_str_slice, less_or_equal_str, greater_than_str}
+const_slice_introsort! {&str, introsort_str_slice, insertion_sort_str_slice, heapsort_str_slice, max_heapify_str_slice, less_than_str, greater_than_str}
 
 const_array_introsort! {&str, introsort_str_array, partition_str_array, insertion_sort_str_array, heapsort_str_array, max_heapify_str_array, greater_than_str, less_than_str}
 
@@ -714,141 +831,539 @@ pub const fn sort_str_slice(slice: &mut [&str]) {
 #[rustversion::since(1.83.0)]
 impl_const_introsort! {f32, f64}
 
-// endregion: introsort implementations
+#[rustversion::since(1.83.0)]
+/// Defines `const` functions that sort floats of the given types according to their IEEE 754 partial order,
+/// i.e. the order given by `partial_cmp`, and panic if the input contains a NaN.
+macro_rules! impl_const_partial_cmp_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s according to [`" $tpe "::partial_cmp`] using the introsort algorithm and returns it."]
+                #[doc = ""]
+                #[doc = "Unlike [`" [<into_sorted_ $tpe _array>] "`], which uses [`" $tpe "::total_cmp`], this treats NaN as an error."]
+                #[doc = "Any input without NaNs is sorted exactly like [`" [<into_sorted_ $tpe _array>] "`] sorts it,"]
+                #[doc = "which is also a valid order in the partial order as it only additionally puts `-0.0` before `0.0`."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the array contains a NaN, as it can not be compared to the other elements."]
+                #[doc = "In a `const` context this is a compile error:"]
+                #[doc = ""]
+                #[doc = "```compile_fail"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_partial_cmp>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_partial_cmp>] "([1.0, " $tpe "::NAN, 0.0]);"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_partial_cmp>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_partial_cmp>] "([1.0, " $tpe "::NEG_INFINITY, 0.0]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [" $tpe "::NEG_INFINITY, 0.0, 1.0]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_partial_cmp>]<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
+                    [<sort_ $tpe _slice_partial_cmp>](&mut array);
+                    array
+                }
 
-// region: counting sort implementations
+                #[doc = "Sorts the given slice of `" $tpe "`s according to [`" $tpe "::partial_cmp`] using the introsort algorithm."]
+                #[doc = ""]
+                #[doc = "Unlike [`" [<sort_ $tpe _slice>] "`], which uses [`" $tpe "::total_cmp`], this treats NaN as an error."]
+                #[doc = "Any input without NaNs is sorted exactly like [`" [<sort_ $tpe _slice>] "`] sorts it,"]
+                #[doc = "which is also a valid order in the partial order as it only additionally puts `-0.0` before `0.0`."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the slice contains a NaN, as it can not be compared to the other elements."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_ $tpe _slice_partial_cmp>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = {"]
+                #[doc = "    let mut arr = [1.0, " $tpe "::NEG_INFINITY, 0.0];"]
+                #[doc = "    " [<sort_ $tpe _slice_partial_cmp>] "(&mut arr);"]
+                #[doc = "    arr"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [" $tpe "::NEG_INFINITY, 0.0, 1.0]);"]
+                #[doc = "```"]
+                pub const fn [<sort_ $tpe _slice_partial_cmp>](slice: &mut [$tpe]) {
+                    let mut i = 0;
+                    while i < slice.len() {
+                        if slice[i].is_nan() {
+                            panic!("NaN can not be sorted according to the partial order of floats");
+                        }
+                        i += 1;
+                    }
+
+                    [<sort_ $tpe _slice>](slice);
+                }
+            }
+        )+
+    };
+}
 
 #[rustversion::since(1.83.0)]
-/// Sorts the given slice of `i8`s using the counting sort algorithm.
-///
-/// Switches to insertion sort when the slice is small.
-///
-/// This function is only available on Rust versions 1.83 and above.
-///
-/// # Example
-///
-/// ```
-/// use compile_time_sort::sort_i8_slice;
-///
-/// const ARRAY: [i8; 3] = [0, i8::MAX, i8::MIN];
-/// const SORTED_ARRAY: [i8; 3] = {
-///     let mut arr = ARRAY;
-///     sort_i8_slice(&mut arr);
-///     arr
-/// };
-///
-/// assert!(SORTED_ARRAY.is_sorted());
-/// ```
-pub const fn sort_i8_slice(slice: &mut [i8]) {
-    if slice.len() <= 1 {
-        return;
-    } else if slice.len() <= INSERTION_SIZE {
-        insertion_sort_i8_slice(slice);
-        return;
-    }
-    let mut counts = [0_usize; u8::MAX as usize + 1];
-    let mut i = 0;
-    let n = slice.len();
-    while i < n {
-        counts[(slice[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
-        i += 1;
-    }
-    i = 0;
-    let mut j = 0;
-    'outer: while i < n {
-        while counts[j] == 0 {
-            if j + 1 > u8::MAX as usize {
-                break 'outer;
+impl_const_partial_cmp_sort! {f32, f64}
+
+#[rustversion::since(1.83.0)]
+/// Defines `const` functions that sort floats and normalize their zeros for the given float types.
+macro_rules! impl_const_canonical_float_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s with [`" [<into_sorted_ $tpe _array>] "`] and then replaces every `-0.0` with `+0.0`."]
+                #[doc = ""]
+                #[doc = "The total order used for sorting puts `-0.0` before `+0.0`, so without the canonicalization a sorted table"]
+                #[doc = "can contain both representations of zero. After it all zeros are the same value, and they are still adjacent"]
+                #[doc = "so the array stays sorted. Use [`" [<into_sorted_dedup_ $tpe _array>] "`] on the result to also collapse them."]
+                #[doc = ""]
+                #[doc = "NaNs are left as they are, and sorted to the ends of the array according to their sign."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_canonical>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 4] = " [<into_sorted_ $tpe _array_canonical>] "([0.0, 1.0, -0.0, -1.0]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY.map(" $tpe "::to_bits), [-1.0, 0.0, 0.0, 1.0].map(" $tpe "::to_bits));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_canonical>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    let mut sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut i = 0;
+                    while i < N {
+                        if sorted[i] == 0.0 {
+                            sorted[i] = 0.0;
+                        }
+                        i += 1;
+                    }
+                    sorted
+                }
             }
-            j += 1;
-        }
-        slice[i] = (j as i16 + i8::MIN.unsigned_abs() as i16) as i8;
-        counts[j] -= 1;
-        i += 1;
-    }
+        )+
+    };
 }
 
 #[rustversion::since(1.83.0)]
-const_slice_insertion_sort!(i8, insertion_sort_i8_slice, greater_than_i8);
+impl_const_canonical_float_sort! {f32, f64}
 
-/// Sorts the given array of `i8`s using the counting sort algorithm and returns it.
-///
-/// Switches to insertion sort when the array is small.
-///
-/// # Example
-///
-/// ```
-/// use compile_time_sort::into_sorted_i8_array;
-///
-/// const SORTED_ARRAY: [i8; 3] = into_sorted_i8_array([0, i8::MAX, i8::MIN]);
+/// Defines `const` functions that sort arrays of the given types with the shellsort algorithm.
+macro_rules! impl_const_shellsort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s using the shellsort algorithm with Ciura's gap sequence and returns it."]
+                #[doc = ""]
+                #[doc = "Shellsort is an insertion sort of elements that are a decreasing gap apart. Unlike [`" [<into_sorted_ $tpe _array>] "`]"]
+                #[doc = "it does not recurse, so it can not hit the recursion limits of `const` evaluation, and it has less overhead than heapsort."]
+                #[doc = "It is slower than introsort on large arrays, as its worst case time complexity is not O(N log N)."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_shell>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 4] = " [<into_sorted_ $tpe _array_shell>] "([3 as " $tpe ", 0 as " $tpe ", 2 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_shell>]<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
+                    // Find the largest extended gap that is still smaller than the array.
+                    let mut gap = CIURA_GAPS[CIURA_GAPS.len() - 1];
+                    while gap * 9 / 4 < N {
+                        gap = gap * 9 / 4;
+                    }
+
+                    let mut gap_index = CIURA_GAPS.len() - 1;
+                    loop {
+                        if gap < N {
+                            let mut i = gap;
+                            while i < N {
+                                let mut j = i;
+                                while j >= gap && [<greater_than_ $tpe>](array[j - gap], array[j]) {
+                                    let temp = array[j - gap];
+                                    array[j - gap] = array[j];
+                                    array[j] = temp;
+                                    j -= gap;
+                                }
+                                i += 1;
+                            }
+                        }
+
+                        if gap > CIURA_GAPS[CIURA_GAPS.len() - 1] {
+                            gap = gap * 4 / 9;
+                            if gap <= CIURA_GAPS[gap_index] {
+                                gap = CIURA_GAPS[gap_index];
+                            }
+                        } else if gap_index == 0 {
+                            return array;
+                        } else {
+                            gap_index -= 1;
+                            gap = CIURA_GAPS[gap_index];
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_shellsort! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_shellsort! {
+    f32, f64
+}
+
+/// Advances the state of the linear congruential generator that chooses the pivots of [`into_sorted_i32_array_seeded`].
+#[inline]
+const fn next_lcg_state(state: u64) -> u64 {
+    state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407)
+}
+
+/// Sorts the elements in the range `left..right` of the given array of `i32`s with a quicksort that picks its pivots
+/// with a linear congruential generator, and returns it.
 ///
-/// assert!(SORTED_ARRAY.is_sorted());
-/// ```
-pub const fn into_sorted_i8_array<const N: usize>(mut array: [i8; N]) -> [i8; N] {
-    if N <= 1 {
-        return array;
-    } else if N <= INSERTION_SIZE {
-        return insertion_sort_i8_array(array);
-    }
-    let mut counts = [0_usize; u8::MAX as usize + 1];
-    let mut i = 0;
-    while i < N {
-        counts[(array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
-        i += 1;
-    }
+/// The elements are partitioned into those less than, equal to, and greater than the pivot, so that runs of equal
+/// elements do not degrade the sort. Only the smaller of the two outer partitions is recursed into, which bounds
+/// the recursion depth to O(log N).
+const fn seeded_quicksort_i32<const N: usize>(
+    mut array: [i32; N],
+    mut state: u64,
+    mut left: usize,
+    mut right: usize,
+) -> [i32; N] {
+    while right - left > INSERTION_SIZE {
+        state = next_lcg_state(state);
+        // The high bits of an LCG are the most random.
+        let pivot = array[left + (state >> 33) as usize % (right - left)];
 
-    i = 0;
-    let mut j = 0;
-    'outer: while i < N {
-        while counts[j] == 0 {
-            if j + 1 > u8::MAX as usize {
-                break 'outer;
+        // Afterwards `array[left..lt]` is less than, `array[lt..gt]` is equal to,
+        // and `array[gt..right]` is greater than the pivot.
+        let mut lt = left;
+        let mut gt = right;
+        let mut i = left;
+        while i < gt {
+            if array[i] < pivot {
+                let temp = array[i];
+                array[i] = array[lt];
+                array[lt] = temp;
+                lt += 1;
+                i += 1;
+            } else if array[i] > pivot {
+                gt -= 1;
+                let temp = array[i];
+                array[i] = array[gt];
+                array[gt] = temp;
+            } else {
+                i += 1;
             }
-            j += 1;
         }
-        array[i] = (j as i16 + i8::MIN.unsigned_abs() as i16) as i8;
-        counts[j] -= 1;
-        i += 1;
-    }
 
-    array
+        if lt - left < right - gt {
+            array = seeded_quicksort_i32(array, state, left, lt);
+            left = gt;
+        } else {
+            array = seeded_quicksort_i32(array, state, gt, right);
+            right = lt;
+        }
+    }
+    insertion_sort_i32_array(array, left, right)
 }
 
-const_array_insertion_sort!(i8, insertion_sort_i8_array, greater_than_i8);
-
-#[rustversion::since(1.83.0)]
-/// Sorts the given slice of `u8`s using the counting sort algorithm.
+/// Sorts the given array of `i32`s using a quicksort whose pivots are chosen by a pseudo-random number generator
+/// that is seeded with the given value, and returns it.
 ///
-/// Switches to insertion sort when the slice is small.
+/// Since the pivots do not depend on the order of the input, no particular input is slow for every seed.
+/// In particular sorted and reverse sorted arrays are sorted in O(N log N) expected time.
+/// The same seed always chooses the same pivots, so the time it takes to sort an array is reproducible across builds.
 ///
-/// This function is only available on Rust versions 1.83 and above.
+/// The sorted array is the same for every seed, only the performance of the sort depends on it.
 ///
 /// # Example
 ///
 /// ```
-/// use compile_time_sort::sort_u8_slice;
+/// use compile_time_sort::into_sorted_i32_array_seeded;
 ///
-/// const ARRAY: [u8; 3] = [0, u8::MAX, u8::MIN];
-/// const SORTED_ARRAY: [u8; 3] = {
+/// const SORTED_ARRAY: [i32; 5] = into_sorted_i32_array_seeded([3, -1, i32::MAX, 0, i32::MIN], 42);
+///
+/// assert_eq!(SORTED_ARRAY, [i32::MIN, -1, 0, 3, i32::MAX]);
+/// ```
+pub const fn into_sorted_i32_array_seeded<const N: usize>(array: [i32; N], seed: u64) -> [i32; N] {
+    seeded_quicksort_i32(array, seed, 0, N)
+}
+
+/// Defines `const` functions that sort arrays of the given types and skip the sorting when they are already sorted.
+macro_rules! impl_const_sorted_or_unchanged {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s if it is not already sorted."]
+                #[doc = ""]
+                #[doc = "Returns `Err(array)` with the unchanged array if it was already sorted, and `Ok(sorted)` if it had to be sorted."]
+                #[doc = "This makes it possible to branch on whether the array changed with a `match` in `const` contexts."]
+                #[doc = "Checking whether the array is sorted takes O(N) time."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_or_unchanged_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const CHANGED: bool = match " [<into_sorted_or_unchanged_ $tpe _array>] "([1 as " $tpe ", 0 as " $tpe "]) {"]
+                #[doc = "    Ok(_) => true,"]
+                #[doc = "    Err(_) => false,"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "assert!(CHANGED);"]
+                #[doc = "assert_eq!(" [<into_sorted_or_unchanged_ $tpe _array>] "([1 as " $tpe ", 0 as " $tpe "]), Ok([0 as " $tpe ", 1 as " $tpe "]));"]
+                #[doc = "assert_eq!(" [<into_sorted_or_unchanged_ $tpe _array>] "([0 as " $tpe ", 1 as " $tpe "]), Err([0 as " $tpe ", 1 as " $tpe "]));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_or_unchanged_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> Result<[$tpe; N], [$tpe; N]> {
+                    if [<sorted_prefix_len_ $tpe _array>](&array) == N {
+                        Err(array)
+                    } else {
+                        Ok([<into_sorted_ $tpe _array>](array))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_or_unchanged! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sorted_or_unchanged! {
+    f32, f64
+}
+
+/// Defines `const` functions named `into_sorted_*_array_smart` for the given types
+/// that are documented aliases of the `into_sorted_*_array` functions.
+///
+/// The versions for `u8` and `i8` check for monotone arrays themselves and are defined in the counting sort region.
+macro_rules! impl_const_smart_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it. This is the same as [`" [<into_sorted_ $tpe _array>] "`]."]
+                #[doc = ""]
+                #[doc = "[`" [<into_sorted_ $tpe _array>] "`] already returns arrays that are sorted in ascending order unchanged"]
+                #[doc = "and reverses arrays that are sorted in descending order, after a single O(N) pass over them."]
+                #[doc = "This alias only exists so that the same name can be used for all types,"]
+                #[doc = "including `u8` and `i8` whose counting sorts do not check for monotone arrays on their own."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_smart>] ";"]
+                #[doc = ""]
+                #[doc = "const DESCENDING: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_smart>] "([2 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(DESCENDING, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_smart>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    [<into_sorted_ $tpe _array>](array)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_smart_sort! {
+    char,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_smart_sort! {
+    f32, f64
+}
+
+/// Defines `const` functions that sort the prefix of arrays of the given types.
+///
+/// The versions for `u8` and `i8` use counting sort and are defined in the counting sort region.
+macro_rules! impl_const_prefix_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the first `len` elements of the given array of `" $tpe "`s using the introsort algorithm and returns it."]
+                #[doc = ""]
+                #[doc = "The elements in `array[len..]` are left untouched. This is meant for fixed-capacity buffers"]
+                #[doc = "where only the first `len` elements are in use."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `len` is greater than `N`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_prefix_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const BUFFER: [" $tpe "; 5] = " [<sort_prefix_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 0 as " $tpe ", 0 as " $tpe "], 3);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(BUFFER, [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe ", 0 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<sort_prefix_ $tpe _array>]<const N: usize>(array: [$tpe; N], len: usize) -> [$tpe; N] {
+                    assert!(len <= N, "the length of the prefix must not be greater than the length of the array");
+
+                    match NonZeroUsize::new(len) {
+                        Some(nz) => {
+                            let max_depth = 2 * ilog2(nz);
+                            [<introsort_ $tpe _array>](array, max_depth, 0, len)
+                        }
+                        None => array,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_prefix_sort! {
+    char,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_prefix_sort! {
+    f32, f64
+}
+
+/// The maximum length of the arrays sorted by the `try_into_sorted_*_array` functions.
+///
+/// Arrays of this length are sorted during `const` evaluation in the `const_eval_sizes` tests,
+/// well within the number of steps the compiler allows before it reports a long running `const` evaluation.
+/// Longer arrays can be sorted at runtime, or in `const` contexts that allow the `long_running_const_eval` lint,
+/// by calling the `into_sorted_*_array` functions directly.
+pub const MAX_CONST_SORT_LEN: usize = 1 << 12;
+
+/// Defines `const` functions that sort arrays of the given types if they are short enough to be sorted during `const` evaluation.
+macro_rules! impl_const_try_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s with [`" [<into_sorted_ $tpe _array>] "`] and returns it,"]
+                #[doc = "or returns [`SortError::TooLargeForConst`] if it has more than [`MAX_CONST_SORT_LEN`] elements."]
+                #[doc = ""]
+                #[doc = "This gives an error that can be handled instead of a compiler that seems to hang on large arrays."]
+                #[doc = "Call [`" [<into_sorted_ $tpe _array>] "`] directly to sort longer arrays, e.g. at runtime."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::{" [<try_into_sorted_ $tpe _array>] ", SortError, MAX_CONST_SORT_LEN};"]
+                #[doc = ""]
+                #[doc = "const SORTED: Result<[" $tpe "; 3], SortError> = " [<try_into_sorted_ $tpe _array>] "([2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = "const TOO_LARGE: Result<[" $tpe "; MAX_CONST_SORT_LEN + 1], SortError> ="]
+                #[doc = "    " [<try_into_sorted_ $tpe _array>] "([0 as " $tpe "; MAX_CONST_SORT_LEN + 1]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, Ok([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]));"]
+                #[doc = "assert_eq!(TOO_LARGE, Err(SortError::TooLargeForConst));"]
+                #[doc = "```"]
+                pub const fn [<try_into_sorted_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> Result<[$tpe; N], SortError> {
+                    if N > MAX_CONST_SORT_LEN {
+                        Err(SortError::TooLargeForConst)
+                    } else {
+                        Ok([<into_sorted_ $tpe _array>](array))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_try_sort! {
+    char,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_try_sort! {f32, f64}
+
+// endregion: introsort implementations
+
+// region: counting sort implementations
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given slice of `i8`s using the counting sort algorithm.
+///
+/// Switches to insertion sort when the slice has 16 or fewer elements. Insertion sort compares the elements to each other,
+/// so unlike [`into_sorted_i8_array_comparison_free`] this function does not guarantee that no comparisons are made.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_i8_slice;
+///
+/// const ARRAY: [i8; 3] = [0, i8::MAX, i8::MIN];
+/// const SORTED_ARRAY: [i8; 3] = {
 ///     let mut arr = ARRAY;
-///     sort_u8_slice(&mut arr);
+///     sort_i8_slice(&mut arr);
 ///     arr
 /// };
 ///
 /// assert!(SORTED_ARRAY.is_sorted());
 /// ```
-pub const fn sort_u8_slice(slice: &mut [u8]) {
+pub const fn sort_i8_slice(slice: &mut [i8]) {
     if slice.len() <= 1 {
         return;
     } else if slice.len() <= INSERTION_SIZE {
-        insertion_sort_u8_slice(slice);
+        insertion_sort_i8_slice(slice);
         return;
     }
     let mut counts = [0_usize; u8::MAX as usize + 1];
     let mut i = 0;
     let n = slice.len();
     while i < n {
-        counts[slice[i] as usize] += 1;
+        counts[(slice[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
         i += 1;
     }
     i = 0;
@@ -860,40 +1375,63 @@ pub const fn sort_u8_slice(slice: &mut [u8]) {
             }
             j += 1;
         }
-        slice[i] = j as u8;
+        slice[i] = (j as i16 + i8::MIN.unsigned_abs() as i16) as i8;
         counts[j] -= 1;
         i += 1;
     }
 }
 
 #[rustversion::since(1.83.0)]
-const_slice_insertion_sort!(u8, insertion_sort_u8_slice, greater_than_u8);
+const_slice_insertion_sort!(i8, insertion_sort_i8_slice, greater_than_i8);
 
-/// Sorts the given array of `u8`s using the counting sort algorithm and returns it.
+/// Sorts the given array of `i8`s using the counting sort algorithm and returns it.
 ///
 /// Switches to insertion sort when the array is small.
 ///
 /// # Example
 ///
 /// ```
-/// use compile_time_sort::into_sorted_u8_array;
+/// use compile_time_sort::into_sorted_i8_array;
 ///
-/// const SORTED_ARRAY: [u8; 3] = into_sorted_u8_array([0, u8::MAX, u8::MIN]);
+/// const SORTED_ARRAY: [i8; 3] = into_sorted_i8_array([0, i8::MAX, i8::MIN]);
 ///
 /// assert!(SORTED_ARRAY.is_sorted());
 /// ```
-pub const fn into_sorted_u8_array<const N: usize>(mut array: [u8; N]) -> [u8; N] {
+pub const fn into_sorted_i8_array<const N: usize>(array: [i8; N]) -> [i8; N] {
     if N <= 1 {
         return array;
     } else if N <= INSERTION_SIZE {
-        return insertion_sort_u8_array(array);
+        return insertion_sort_i8_array(array, 0, N);
     }
+    into_sorted_i8_array_comparison_free(array)
+}
+
+const_array_insertion_sort!(i8, insertion_sort_i8_array, greater_than_i8);
+
+/// Sorts the given array of `i8`s using the counting sort algorithm and returns it,
+/// without ever comparing two elements to each other.
+///
+/// Unlike [`into_sorted_i8_array`] this does not switch to insertion sort for small arrays.
+/// The elements are only used as indices into a histogram of 256 counts, which is then written back in order,
+/// so the running time is O(N + 256) regardless of the order of the input.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_i8_array_comparison_free;
+///
+/// const SORTED_ARRAY: [i8; 3] = into_sorted_i8_array_comparison_free([0, i8::MAX, i8::MIN]);
+///
+/// assert_eq!(SORTED_ARRAY, [i8::MIN, 0, i8::MAX]);
+/// ```
+pub const fn into_sorted_i8_array_comparison_free<const N: usize>(mut array: [i8; N]) -> [i8; N] {
     let mut counts = [0_usize; u8::MAX as usize + 1];
     let mut i = 0;
     while i < N {
-        counts[array[i] as usize] += 1;
+        counts[(array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
         i += 1;
     }
+
     i = 0;
     let mut j = 0;
     'outer: while i < N {
@@ -903,103 +1441,4862 @@ pub const fn into_sorted_u8_array<const N: usize>(mut array: [u8; N]) -> [u8; N]
             }
             j += 1;
         }
-        array[i] = j as u8;
+        array[i] = (j as i16 + i8::MIN.unsigned_abs() as i16) as i8;
         counts[j] -= 1;
         i += 1;
     }
+
     array
 }
 
-const_array_insertion_sort!(u8, insertion_sort_u8_array, greater_than_u8);
-
-#[rustversion::since(1.83.0)]
-/// Sorts the given slice of `bool`s using the counting sort algorithm.
+#[rustversion::since(1.57.0)]
+/// Sorts the first `len` elements of the given array of `i8`s using the counting sort algorithm and returns it.
 ///
-/// This function is only available on Rust versions 1.83 and above.
+/// The elements in `array[len..]` are left untouched. This is meant for fixed-capacity buffers
+/// where only the first `len` elements are in use.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if `len` is greater than `N`.
 ///
 /// # Example
 ///
 /// ```
-/// use compile_time_sort::sort_bool_slice;
+/// use compile_time_sort::sort_prefix_i8_array;
 ///
-/// const ARRAY: [bool; 4] = [true, false, true, false];
-/// const SORTED_ARRAY: [bool; 4] = {
-///     let mut arr = ARRAY;
-///     sort_bool_slice(&mut arr);
-///     arr
-/// };
+/// const BUFFER: [i8; 5] = sort_prefix_i8_array([3, -1, 2, 0, 0], 3);
 ///
-/// assert!(SORTED_ARRAY.is_sorted());
+/// assert_eq!(BUFFER, [-1, 2, 3, 0, 0]);
 /// ```
-pub const fn sort_bool_slice(slice: &mut [bool]) {
-    if slice.len() <= 1 {
-        return;
+pub const fn sort_prefix_i8_array<const N: usize>(mut array: [i8; N], len: usize) -> [i8; N] {
+    assert!(
+        len <= N,
+        "the length of the prefix must not be greater than the length of the array"
+    );
+
+    if len <= INSERTION_SIZE {
+        return insertion_sort_i8_array(array, 0, len);
     }
-    let mut falses = 0;
+    let mut counts = [0_usize; u8::MAX as usize + 1];
     let mut i = 0;
-    let n = slice.len();
-    while i < n {
-        if !slice[i] {
-            falses += 1;
-        }
+    while i < len {
+        counts[(array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
         i += 1;
     }
 
     i = 0;
-    while i < n {
-        if falses > 0 {
-            slice[i] = false;
-            falses -= 1;
-        } else {
-            slice[i] = true;
+    let mut j = 0;
+    while i < len {
+        while counts[j] == 0 {
+            j += 1;
         }
+        array[i] = (j as i16 + i8::MIN.unsigned_abs() as i16) as i8;
+        counts[j] -= 1;
         i += 1;
     }
+
+    array
 }
 
-/// Sorts the given array of `bool`s using the counting sort algorithm and returns it.
+#[rustversion::since(1.83.0)]
+/// Sorts the given slice of `u8`s using the counting sort algorithm.
+///
+/// Switches to insertion sort when the slice has 16 or fewer elements. Insertion sort compares the elements to each other,
+/// so unlike [`into_sorted_u8_array_comparison_free`] this function does not guarantee that no comparisons are made.
+///
+/// This function is only available on Rust versions 1.83 and above.
 ///
 /// # Example
 ///
 /// ```
-/// use compile_time_sort::into_sorted_bool_array;
+/// use compile_time_sort::sort_u8_slice;
 ///
-/// const SORTED_ARRAY: [bool; 4] = into_sorted_bool_array([true, false, true, false]);
+/// const ARRAY: [u8; 3] = [0, u8::MAX, u8::MIN];
+/// const SORTED_ARRAY: [u8; 3] = {
+///     let mut arr = ARRAY;
+///     sort_u8_slice(&mut arr);
+///     arr
+/// };
 ///
 /// assert!(SORTED_ARRAY.is_sorted());
 /// ```
-pub const fn into_sorted_bool_array<const N: usize>(mut array: [bool; N]) -> [bool; N] {
-    if N <= 1 {
-        return array;
-    }
-    let mut falses = 0;
-    let mut i = 0;
-    while i < N {
-        if !array[i] {
-            falses += 1;
+pub const fn sort_u8_slice(slice: &mut [u8]) {
+    sort_u8_slice_with_counts(slice, &mut [0; u8::MAX as usize + 1]);
+}
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given slice of `u8`s using the counting sort algorithm with a caller provided buffer for the counts.
+///
+/// Switches to insertion sort when the slice has 16 or fewer elements. Insertion sort compares the elements to each other,
+/// so unlike [`into_sorted_u8_array_comparison_free`] this function does not guarantee that no comparisons are made.
+///
+/// The buffer is zeroed before it is used, so it can be reused between calls without being reset.
+/// This avoids creating a new buffer of 256 counts on the stack for every sorted slice.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_u8_slice_with_counts;
+///
+/// const SORTED_ARRAYS: [[u8; 3]; 2] = {
+///     let mut counts = [0; 256];
+///     let mut arrays = [[0, u8::MAX, 1], [2, 1, 0]];
+///     sort_u8_slice_with_counts(&mut arrays[0], &mut counts);
+///     sort_u8_slice_with_counts(&mut arrays[1], &mut counts);
+///     arrays
+/// };
+///
+/// assert_eq!(SORTED_ARRAYS, [[0, 1, u8::MAX], [0, 1, 2]]);
+/// ```
+pub const fn sort_u8_slice_with_counts(
+    slice: &mut [u8],
+    counts: &mut [usize; u8::MAX as usize + 1],
+) {
+    if slice.len() <= 1 {
+        return;
+    } else if slice.len() <= INSERTION_SIZE {
+        insertion_sort_u8_slice(slice);
+        return;
+    }
+    let mut i = 0;
+    while i < counts.len() {
+        counts[i] = 0;
+        i += 1;
+    }
+    i = 0;
+    let n = slice.len();
+    while i < n {
+        counts[slice[i] as usize] += 1;
+        i += 1;
+    }
+    i = 0;
+    let mut j = 0;
+    'outer: while i < n {
+        while counts[j] == 0 {
+            if j + 1 > u8::MAX as usize {
+                break 'outer;
+            }
+            j += 1;
+        }
+        slice[i] = j as u8;
+        counts[j] -= 1;
+        i += 1;
+    }
+}
+
+#[rustversion::since(1.83.0)]
+const_slice_insertion_sort!(u8, insertion_sort_u8_slice, greater_than_u8);
+
+/// Sorts the given array of `u8`s using the counting sort algorithm and returns it.
+///
+/// Switches to insertion sort when the array is small.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u8_array;
+///
+/// const SORTED_ARRAY: [u8; 3] = into_sorted_u8_array([0, u8::MAX, u8::MIN]);
+///
+/// assert!(SORTED_ARRAY.is_sorted());
+/// ```
+pub const fn into_sorted_u8_array<const N: usize>(array: [u8; N]) -> [u8; N] {
+    if N <= 1 {
+        return array;
+    } else if N <= INSERTION_SIZE {
+        return insertion_sort_u8_array(array, 0, N);
+    }
+    into_sorted_u8_array_comparison_free(array)
+}
+
+const_array_insertion_sort!(u8, insertion_sort_u8_array, greater_than_u8);
+
+/// Sorts the given array of `u8`s using the counting sort algorithm and returns it,
+/// without ever comparing two elements to each other.
+///
+/// Unlike [`into_sorted_u8_array`] this does not switch to insertion sort for small arrays.
+/// The elements are only used as indices into a histogram of 256 counts, which is then written back in order,
+/// so the running time is O(N + 256) regardless of the order of the input.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u8_array_comparison_free;
+///
+/// const SORTED_ARRAY: [u8; 3] = into_sorted_u8_array_comparison_free([2, u8::MAX, u8::MIN]);
+///
+/// assert_eq!(SORTED_ARRAY, [u8::MIN, 2, u8::MAX]);
+/// ```
+pub const fn into_sorted_u8_array_comparison_free<const N: usize>(mut array: [u8; N]) -> [u8; N] {
+    let mut counts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
+    while i < N {
+        counts[array[i] as usize] += 1;
+        i += 1;
+    }
+    i = 0;
+    let mut j = 0;
+    'outer: while i < N {
+        while counts[j] == 0 {
+            if j + 1 > u8::MAX as usize {
+                break 'outer;
+            }
+            j += 1;
+        }
+        array[i] = j as u8;
+        counts[j] -= 1;
+        i += 1;
+    }
+    array
+}
+
+#[rustversion::since(1.57.0)]
+/// Sorts the first `len` elements of the given array of `u8`s using the counting sort algorithm and returns it.
+///
+/// The elements in `array[len..]` are left untouched. This is meant for fixed-capacity buffers
+/// where only the first `len` elements are in use.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if `len` is greater than `N`.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_prefix_u8_array;
+///
+/// const BUFFER: [u8; 5] = sort_prefix_u8_array([3, 1, 2, 0, 0], 3);
+///
+/// assert_eq!(BUFFER, [1, 2, 3, 0, 0]);
+/// ```
+pub const fn sort_prefix_u8_array<const N: usize>(mut array: [u8; N], len: usize) -> [u8; N] {
+    assert!(
+        len <= N,
+        "the length of the prefix must not be greater than the length of the array"
+    );
+
+    if len <= INSERTION_SIZE {
+        return insertion_sort_u8_array(array, 0, len);
+    }
+    let mut counts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
+    while i < len {
+        counts[array[i] as usize] += 1;
+        i += 1;
+    }
+
+    i = 0;
+    let mut j = 0;
+    while i < len {
+        while counts[j] == 0 {
+            j += 1;
+        }
+        array[i] = j as u8;
+        counts[j] -= 1;
+        i += 1;
+    }
+
+    array
+}
+
+/// Defines `const` functions that sort arrays of the given types with counting sort,
+/// with fast paths for arrays that are already sorted in ascending or descending order.
+macro_rules! impl_const_counting_smart_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it, with fast paths for arrays that are already monotone."]
+                #[doc = ""]
+                #[doc = "Returns the array unchanged if it is already sorted in ascending order, reverses it if it is sorted in descending order,"]
+                #[doc = "and otherwise sorts it with [`" [<into_sorted_ $tpe _array>] "`]."]
+                #[doc = "This is meant for hand-written tables that are usually monotone in one direction."]
+                #[doc = ""]
+                #[doc = "Unlike the introsort of the other types, the counting sort of [`" [<into_sorted_ $tpe _array>] "`]"]
+                #[doc = "does not check for monotone arrays on its own. The checks take up to two O(N) passes over the array,"]
+                #[doc = "which are wasted if it turns out not to be monotone."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_smart>] ";"]
+                #[doc = ""]
+                #[doc = "const ASCENDING: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_smart>] "([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "const DESCENDING: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_smart>] "([2 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = "const UNSORTED: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_smart>] "([1 as " $tpe ", 2 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(ASCENDING, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "assert_eq!(DESCENDING, ASCENDING);"]
+                #[doc = "assert_eq!(UNSORTED, ASCENDING);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_smart>]<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
+                    if [<sorted_prefix_len_ $tpe _array>](&array) == N {
+                        return array;
+                    }
+
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](array[i - 1], array[i]) {
+                            return [<into_sorted_ $tpe _array>](array);
+                        }
+                        i += 1;
+                    }
+
+                    // The array is sorted in descending order, so reversing it sorts it.
+                    i = 0;
+                    while i < N / 2 {
+                        let temp = array[i];
+                        array[i] = array[N - 1 - i];
+                        array[N - 1 - i] = temp;
+                        i += 1;
+                    }
+                    array
+                }
+            }
+        )+
+    };
+}
+
+impl_const_counting_smart_sort! {u8, i8}
+
+/// Sorts the given array of `u8`s by their rank in the given table using the counting sort algorithm and returns it.
+///
+/// The rank of a value `x` is `rank[x as usize]`, and the values are sorted in ascending order of their ranks.
+/// This makes it possible to sort by any custom order of the bytes.
+/// The sort is stable, so values with equal ranks keep their order from the input.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u8_array_by_rank;
+///
+/// // Card ranks where an ace (1) is ranked above a king (13).
+/// const RANK: [u8; 256] = {
+///     let mut rank = [0; 256];
+///     let mut i = 0;
+///     while i < 256 {
+///         rank[i] = i as u8;
+///         i += 1;
+///     }
+///     rank[1] = 14;
+///     rank
+/// };
+///
+/// const SORTED_ARRAY: [u8; 4] = into_sorted_u8_array_by_rank([1, 13, 2, 10], &RANK);
+///
+/// assert_eq!(SORTED_ARRAY, [2, 10, 13, 1]);
+/// ```
+pub const fn into_sorted_u8_array_by_rank<const N: usize>(
+    array: [u8; N],
+    rank: &[u8; 256],
+) -> [u8; N] {
+    let mut starts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
+    while i < N {
+        starts[rank[array[i] as usize] as usize] += 1;
+        i += 1;
+    }
+
+    // Turn the counts into the index where the first value with each rank goes.
+    let mut total = 0;
+    i = 0;
+    while i < starts.len() {
+        let count = starts[i];
+        starts[i] = total;
+        total += count;
+        i += 1;
+    }
+
+    let mut sorted = array;
+    i = 0;
+    while i < N {
+        let r = rank[array[i] as usize] as usize;
+        sorted[starts[r]] = array[i];
+        starts[r] += 1;
+        i += 1;
+    }
+    sorted
+}
+
+#[inline]
+const fn greater_than_u8_pair(a: (u8, u8), b: (u8, u8)) -> bool {
+    a.0 > b.0 || (a.0 == b.0 && a.1 > b.1)
+}
+
+/// Sorts the given array of `(u8, u8)` pairs in lexicographic order using the counting sort algorithm and returns it.
+///
+/// Every pair is packed into a `u16` which indexes into a histogram with 65536 buckets,
+/// so this runs in O(N + 65536) time. Note that the histogram takes up 512 KiB of stack space
+/// on 64-bit targets when this function is called at runtime.
+///
+/// Switches to insertion sort when the array is small.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u8_pair_array;
+///
+/// const SORTED_ARRAY: [(u8, u8); 3] = into_sorted_u8_pair_array([(1, 0), (0, u8::MAX), (0, 1)]);
+///
+/// assert_eq!(SORTED_ARRAY, [(0, 1), (0, u8::MAX), (1, 0)]);
+/// ```
+pub const fn into_sorted_u8_pair_array<const N: usize>(mut array: [(u8, u8); N]) -> [(u8, u8); N] {
+    if N <= 1 {
+        return array;
+    } else if N <= INSERTION_SIZE {
+        return insertion_sort_u8_pair_array(array, 0, N);
+    }
+    let mut counts = [0_usize; u16::MAX as usize + 1];
+    let mut i = 0;
+    while i < N {
+        counts[((array[i].0 as u16) << 8 | array[i].1 as u16) as usize] += 1;
+        i += 1;
+    }
+    i = 0;
+    let mut j = 0;
+    'outer: while i < N {
+        while counts[j] == 0 {
+            if j + 1 > u16::MAX as usize {
+                break 'outer;
+            }
+            j += 1;
+        }
+        array[i] = ((j >> 8) as u8, j as u8);
+        counts[j] -= 1;
+        i += 1;
+    }
+    array
+}
+
+const_array_insertion_sort!((u8, u8), insertion_sort_u8_pair_array, greater_than_u8_pair);
+
+#[inline]
+const fn greater_than_nonzero_u8(a: NonZeroU8, b: NonZeroU8) -> bool {
+    a.get() > b.get()
+}
+
+/// Sorts the given array of [`NonZeroU8`]s using the counting sort algorithm and returns it.
+///
+/// Uses a histogram with one bucket for each of the 255 possible values.
+/// The sorted values are copied from the input, so no `NonZeroU8` is ever constructed from a raw byte.
+///
+/// Switches to insertion sort when the array is small.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_nonzero_u8_array;
+/// use core::num::NonZeroU8;
+///
+/// const ARRAY: [NonZeroU8; 3] = match (NonZeroU8::new(3), NonZeroU8::new(1), NonZeroU8::new(2)) {
+///     (Some(a), Some(b), Some(c)) => [a, b, c],
+///     _ => panic!("the values are not zero"),
+/// };
+/// const SORTED_ARRAY: [NonZeroU8; 3] = into_sorted_nonzero_u8_array(ARRAY);
+///
+/// assert_eq!(SORTED_ARRAY.map(NonZeroU8::get), [1, 2, 3]);
+/// ```
+pub const fn into_sorted_nonzero_u8_array<const N: usize>(
+    mut array: [NonZeroU8; N],
+) -> [NonZeroU8; N] {
+    if N <= 1 {
+        return array;
+    } else if N <= INSERTION_SIZE {
+        return insertion_sort_nonzero_u8_array(array, 0, N);
+    }
+    let mut counts = [0_usize; u8::MAX as usize];
+    let mut values = [array[0]; u8::MAX as usize];
+    let mut i = 0;
+    while i < N {
+        let index = array[i].get() as usize - 1;
+        counts[index] += 1;
+        values[index] = array[i];
+        i += 1;
+    }
+    i = 0;
+    let mut j = 0;
+    'outer: while i < N {
+        while counts[j] == 0 {
+            if j + 1 >= u8::MAX as usize {
+                break 'outer;
+            }
+            j += 1;
+        }
+        array[i] = values[j];
+        counts[j] -= 1;
+        i += 1;
+    }
+    array
+}
+
+const_array_insertion_sort!(
+    NonZeroU8,
+    insertion_sort_nonzero_u8_array,
+    greater_than_nonzero_u8
+);
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given slice of `bool`s using the counting sort algorithm.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_bool_slice;
+///
+/// const ARRAY: [bool; 4] = [true, false, true, false];
+/// const SORTED_ARRAY: [bool; 4] = {
+///     let mut arr = ARRAY;
+///     sort_bool_slice(&mut arr);
+///     arr
+/// };
+///
+/// assert!(SORTED_ARRAY.is_sorted());
+/// ```
+pub const fn sort_bool_slice(slice: &mut [bool]) {
+    if slice.len() <= 1 {
+        return;
+    }
+    let mut falses = 0;
+    let mut i = 0;
+    let n = slice.len();
+    while i < n {
+        if !slice[i] {
+            falses += 1;
+        }
+        i += 1;
+    }
+
+    i = 0;
+    while i < n {
+        if falses > 0 {
+            slice[i] = false;
+            falses -= 1;
+        } else {
+            slice[i] = true;
+        }
+        i += 1;
+    }
+}
+
+/// Sorts the given array of `bool`s using the counting sort algorithm and returns it.
+///
+/// This never compares two elements to each other, it only counts the `false`s and writes them back before the `true`s.
+/// See [`into_sorted_u8_array_comparison_free`] and [`into_sorted_i8_array_comparison_free`] for the other types
+/// with this guarantee.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_bool_array;
+///
+/// const SORTED_ARRAY: [bool; 4] = into_sorted_bool_array([true, false, true, false]);
+///
+/// assert!(SORTED_ARRAY.is_sorted());
+/// ```
+pub const fn into_sorted_bool_array<const N: usize>(mut array: [bool; N]) -> [bool; N] {
+    if N <= 1 {
+        return array;
+    }
+    let mut falses = 0;
+    let mut i = 0;
+    while i < N {
+        if !array[i] {
+            falses += 1;
+        }
+        i += 1;
+    }
+
+    i = 0;
+    while i < N {
+        if falses > 0 {
+            array[i] = false;
+            falses -= 1;
+        } else {
+            array[i] = true;
+        }
+        i += 1;
+    }
+
+    array
+}
+
+/// Sorts the given array of `bool`s using the counting sort algorithm and returns it
+/// together with the index of its first `true`, which is the number of `false`s in it.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_bool_array_with_split;
+///
+/// const SPLIT: ([bool; 4], usize) = into_sorted_bool_array_with_split([true, false, true, true]);
+///
+/// assert_eq!(SPLIT, ([false, true, true, true], 1));
+/// ```
+pub const fn into_sorted_bool_array_with_split<const N: usize>(
+    mut array: [bool; N],
+) -> ([bool; N], usize) {
+    let mut falses = 0;
+    let mut i = 0;
+    while i < N {
+        if !array[i] {
+            falses += 1;
+        }
+        i += 1;
+    }
+
+    i = 0;
+    while i < N {
+        array[i] = i >= falses;
+        i += 1;
+    }
+
+    (array, falses)
+}
+
+/// Sorts the given array of `bool`s in descending order, i.e. with all `true`s first,
+/// using the counting sort algorithm and returns it.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_bool_array_desc;
+///
+/// const SORTED_ARRAY: [bool; 4] = into_sorted_bool_array_desc([false, true, false, true]);
+///
+/// assert_eq!(SORTED_ARRAY, [true, true, false, false]);
+/// ```
+pub const fn into_sorted_bool_array_desc<const N: usize>(mut array: [bool; N]) -> [bool; N] {
+    let mut trues = 0;
+    let mut i = 0;
+    while i < N {
+        if array[i] {
+            trues += 1;
+        }
+        i += 1;
+    }
+
+    i = 0;
+    while i < N {
+        array[i] = i < trues;
+        i += 1;
+    }
+
+    array
+}
+
+#[rustversion::since(1.57.0)]
+/// Returns a `u64` where the lowest `bits` bits are set.
+const fn low_bits_u64(bits: u32) -> u64 {
+    match 1_u64.checked_shl(bits) {
+        Some(bit) => bit - 1,
+        None => u64::MAX,
+    }
+}
+
+#[rustversion::since(1.57.0)]
+/// Sorts the `count` `bool`s that are packed as the lowest bits of the given `u64` and returns them packed the same way.
+///
+/// Bit `i` of the mask is element `i` of the array, so this is the bitmask analogue of [`into_sorted_bool_array`]:
+/// the returned mask has as many set bits as the input, but they are all moved above the clear bits.
+/// Bits at or above `count` are ignored, and are clear in the returned mask.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if `count` is greater than 64.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_bool_bits_u64;
+///
+/// // The array [true, false, true, false, false].
+/// const SORTED: u64 = sort_bool_bits_u64(0b00101, 5);
+///
+/// // The array [false, false, false, true, true].
+/// assert_eq!(SORTED, 0b11000);
+/// ```
+pub const fn sort_bool_bits_u64(mask: u64, count: u8) -> u64 {
+    assert!(count <= 64, "a `u64` can not hold more than 64 `bool`s");
+    let count = count as u32;
+    let trues = (mask & low_bits_u64(count)).count_ones();
+    low_bits_u64(count) & !low_bits_u64(count - trues)
+}
+
+/// Defines public `const` functions that sort arrays and slices of the given 16-bit integer type
+/// with the counting sort algorithm.
+///
+/// The elements are mapped to an index into the counts by converting them to `i32` and adding the given bias.
+macro_rules! impl_const_16_bit_counting_sort {
+    ($tpe:ty, $bias:expr) => {
+        paste::paste! {
+            #[doc = "Sorts the given array of `" $tpe "`s using the counting sort algorithm and returns it."]
+            #[doc = ""]
+            #[doc = "The running time is O(N + 65 536) regardless of the order of the input, which can beat"]
+            #[doc = "[`" [<into_sorted_ $tpe _array>] "`] for long arrays."]
+            #[doc = ""]
+            #[doc = "The counts take up 65 536 `usize`s on the stack, which is 512 KiB on 64-bit targets."]
+            #[doc = "This may overflow the stack at runtime, e.g. on embedded targets or on threads with small stacks,"]
+            #[doc = "which is why [`" [<into_sorted_ $tpe _array>] "`] does not use counting sort."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_counting>] ";"]
+            #[doc = ""]
+            #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_counting>] "([0, " $tpe "::MAX, " $tpe "::MIN]);"]
+            #[doc = ""]
+            #[doc = "assert_eq!(SORTED_ARRAY, [" $tpe "::MIN, 0, " $tpe "::MAX]);"]
+            #[doc = "```"]
+            pub const fn [<into_sorted_ $tpe _array_counting>]<const N: usize>(mut array: [$tpe; N]) -> [$tpe; N] {
+                let mut counts = [0_usize; u16::MAX as usize + 1];
+                let mut i = 0;
+                while i < N {
+                    counts[(array[i] as i32 + $bias) as usize] += 1;
+                    i += 1;
+                }
+
+                // The counts add up to `N`, so this never runs past the end of them.
+                i = 0;
+                let mut j = 0;
+                while i < N {
+                    while counts[j] == 0 {
+                        j += 1;
+                    }
+                    array[i] = (j as i32 - $bias) as $tpe;
+                    counts[j] -= 1;
+                    i += 1;
+                }
+
+                array
+            }
+
+            #[rustversion::since(1.83.0)]
+            #[doc = "Sorts the given slice of `" $tpe "`s using the counting sort algorithm."]
+            #[doc = ""]
+            #[doc = "The running time is O(N + 65 536) regardless of the order of the input, which can beat"]
+            #[doc = "[`" [<sort_ $tpe _slice>] "`] for long slices."]
+            #[doc = ""]
+            #[doc = "The counts take up 65 536 `usize`s on the stack, which is 512 KiB on 64-bit targets."]
+            #[doc = "This may overflow the stack at runtime, e.g. on embedded targets or on threads with small stacks,"]
+            #[doc = "which is why [`" [<sort_ $tpe _slice>] "`] does not use counting sort."]
+            #[doc = ""]
+            #[doc = "This function is only available on Rust versions 1.83 and above."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = "use compile_time_sort::" [<sort_ $tpe _slice_counting>] ";"]
+            #[doc = ""]
+            #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = {"]
+            #[doc = "    let mut arr = [0, " $tpe "::MAX, " $tpe "::MIN];"]
+            #[doc = "    " [<sort_ $tpe _slice_counting>] "(&mut arr);"]
+            #[doc = "    arr"]
+            #[doc = "};"]
+            #[doc = ""]
+            #[doc = "assert_eq!(SORTED_ARRAY, [" $tpe "::MIN, 0, " $tpe "::MAX]);"]
+            #[doc = "```"]
+            pub const fn [<sort_ $tpe _slice_counting>](slice: &mut [$tpe]) {
+                let mut counts = [0_usize; u16::MAX as usize + 1];
+                let mut i = 0;
+                while i < slice.len() {
+                    counts[(slice[i] as i32 + $bias) as usize] += 1;
+                    i += 1;
+                }
+
+                i = 0;
+                let mut j = 0;
+                while i < slice.len() {
+                    while counts[j] == 0 {
+                        j += 1;
+                    }
+                    slice[i] = (j as i32 - $bias) as $tpe;
+                    counts[j] -= 1;
+                    i += 1;
+                }
+            }
+        }
+    };
+}
+
+impl_const_16_bit_counting_sort!(u16, 0);
+impl_const_16_bit_counting_sort!(i16, i16::MIN.unsigned_abs() as i32);
+
+/// The recommended maximum length of the arrays sorted by the `into_sorted_*_array_bounded` functions.
+///
+/// The counting sorts only need a fixed size buffer of counts, but the array itself is passed by value.
+/// Arrays of this many one-byte elements take up 64 KiB, which comfortably fits on the stack
+/// and is fast to sort during `const` evaluation.
+pub const RECOMMENDED_MAX_COUNTING_SORT_LEN: usize = 1 << 16;
+
+/// Defines `const` functions that sort arrays of the given types with counting sort,
+/// but refuse to sort arrays that are longer than a given bound.
+macro_rules! impl_const_bounded_counting_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s with [`" [<into_sorted_ $tpe _array>] "`] if it has at most `MAX_N` elements."]
+                #[doc = ""]
+                #[doc = "This guards against accidentally sorting arrays that are so large that they might overflow the stack,"]
+                #[doc = "either at runtime or during `const` evaluation. The recommended bound is [`RECOMMENDED_MAX_COUNTING_SORT_LEN`]."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `N` is greater than `MAX_N`. In a `const` context this is a compile error:"]
+                #[doc = ""]
+                #[doc = "```compile_fail"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_bounded>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_bounded>] "::<3, 2>([" $tpe "::MAX, " $tpe "::MIN, " $tpe "::MAX]);"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::{" [<into_sorted_ $tpe _array_bounded>] ", RECOMMENDED_MAX_COUNTING_SORT_LEN};"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] ="]
+                #[doc = "    " [<into_sorted_ $tpe _array_bounded>] "::<3, RECOMMENDED_MAX_COUNTING_SORT_LEN>([" $tpe "::MAX, " $tpe "::MIN, " $tpe "::MAX]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [" $tpe "::MIN, " $tpe "::MAX, " $tpe "::MAX]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_bounded>]<const N: usize, const MAX_N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    assert!(N <= MAX_N, "the array is longer than the given bound");
+                    [<into_sorted_ $tpe _array>](array)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_bounded_counting_sort! {u8, i8}
+
+#[rustversion::since(1.57.0)]
+/// Sorts the given array of `bool`s with [`into_sorted_bool_array`] if it has at most `MAX_N` elements.
+///
+/// This guards against accidentally sorting arrays that are so large that they might overflow the stack,
+/// either at runtime or during `const` evaluation. The recommended bound is [`RECOMMENDED_MAX_COUNTING_SORT_LEN`].
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if `N` is greater than `MAX_N`.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::{into_sorted_bool_array_bounded, RECOMMENDED_MAX_COUNTING_SORT_LEN};
+///
+/// const SORTED_ARRAY: [bool; 3] =
+///     into_sorted_bool_array_bounded::<3, RECOMMENDED_MAX_COUNTING_SORT_LEN>([true, false, true]);
+///
+/// assert_eq!(SORTED_ARRAY, [false, true, true]);
+/// ```
+pub const fn into_sorted_bool_array_bounded<const N: usize, const MAX_N: usize>(
+    array: [bool; N],
+) -> [bool; N] {
+    assert!(N <= MAX_N, "the array is longer than the given bound");
+    into_sorted_bool_array(array)
+}
+
+/// Implements counting sort for arrays of the given fieldless `#[repr(u8)]` enums.
+///
+/// For every given enum this adds an associated function `into_sorted_array` that sorts an array of that enum
+/// by the discriminants of its variants. The enum must implement [`Copy`].
+///
+/// The sort is done without any `unsafe` code: the discriminants are read with an `as u8` cast,
+/// and the variants are written back by copying them from the input array.
+/// Only `#[repr(u8)]` enums are supported. It is a compile error to invoke this macro on an enum that is larger than a byte,
+/// and sorting an array that contains a variant with a negative discriminant, e.g. of a `#[repr(i8)]` enum, panics.
+/// In a `const` context this is a compile error. Since panicking in a `const fn` needs Rust 1.57,
+/// the panic is caused by indexing out of bounds, so its message is about an index.
+///
+/// ```compile_fail
+/// use compile_time_sort::impl_sort_for_repr_u8_enum;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(i8)]
+/// enum Signed {
+///     Negative = -1,
+///     Positive = 1,
+/// }
+///
+/// impl_sort_for_repr_u8_enum!(Signed);
+///
+/// const SORTED_ARRAY: [Signed; 2] = Signed::into_sorted_array([Signed::Positive, Signed::Negative]);
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::impl_sort_for_repr_u8_enum;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// #[repr(u8)]
+/// enum Priority {
+///     Low = 0,
+///     Mid = 1,
+///     High = 2,
+/// }
+///
+/// impl_sort_for_repr_u8_enum!(Priority);
+///
+/// const SORTED_ARRAY: [Priority; 4] =
+///     Priority::into_sorted_array([Priority::High, Priority::Low, Priority::Mid, Priority::Low]);
+///
+/// assert_eq!(SORTED_ARRAY, [Priority::Low, Priority::Low, Priority::Mid, Priority::High]);
+/// ```
+#[macro_export]
+macro_rules! impl_sort_for_repr_u8_enum {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            const _: [(); 1] = [(); (::core::mem::size_of::<$name>() == 1) as usize];
+
+            impl $name {
+                /// Sorts the given array by the discriminants of its elements
+                /// using the counting sort algorithm and returns it.
+                ///
+                /// # Panics
+                ///
+                /// Panics if any element has a negative discriminant.
+                pub const fn into_sorted_array<const N: usize>(mut array: [Self; N]) -> [Self; N] {
+                    if N == 0 {
+                        return array;
+                    }
+                    let mut counts = [0_usize; u8::MAX as usize + 1];
+                    let mut variants = [array[0]; u8::MAX as usize + 1];
+                    let mut i = 0;
+                    while i < N {
+                        // A negative discriminant would wrap around in the cast to `u8` and be sorted after the positive ones,
+                        // so it is rejected by indexing out of bounds.
+                        let _negative_discriminant_is_not_supported = [(); 1][((array[i] as i16) < 0) as usize];
+                        let discriminant = array[i] as u8 as usize;
+                        counts[discriminant] += 1;
+                        variants[discriminant] = array[i];
+                        i += 1;
+                    }
+                    i = 0;
+                    let mut j = 0;
+                    while i < N {
+                        while counts[j] == 0 {
+                            j += 1;
+                        }
+                        array[i] = variants[j];
+                        counts[j] -= 1;
+                        i += 1;
+                    }
+                    array
+                }
+            }
+        )+
+    };
+}
+
+/// Sorts the given array of `u32`s with a least significant digit radix sort that does
+/// one stable counting sort pass per byte, and returns it.
+const fn radix_sort_u32<const N: usize>(mut array: [u32; N]) -> [u32; N] {
+    let mut scratch = [0_u32; N];
+    let mut shift = 0;
+    while shift < u32::BITS {
+        let mut counts = [0_usize; u8::MAX as usize + 1];
+        let mut i = 0;
+        while i < N {
+            counts[((array[i] >> shift) & 0xFF) as usize] += 1;
+            i += 1;
+        }
+
+        // If every element has the same byte the pass would not move anything.
+        if N > 0 && counts[((array[0] >> shift) & 0xFF) as usize] == N {
+            shift += 8;
+            continue;
+        }
+
+        // Turn the counts into the index where the elements with each byte start.
+        let mut start = 0;
+        i = 0;
+        while i < counts.len() {
+            let count = counts[i];
+            counts[i] = start;
+            start += count;
+            i += 1;
+        }
+
+        i = 0;
+        while i < N {
+            let byte = ((array[i] >> shift) & 0xFF) as usize;
+            scratch[counts[byte]] = array[i];
+            counts[byte] += 1;
+            i += 1;
+        }
+
+        let temp = array;
+        array = scratch;
+        scratch = temp;
+        shift += 8;
+    }
+    array
+}
+
+/// Sorts the given array of `u32`s using the radix sort algorithm and returns it.
+///
+/// The sort does up to four stable counting sort passes, one for each byte of the elements, so it runs in O(N) time.
+/// Passes over bytes that are the same in every element are skipped. It uses a scratch array of the same size as the input.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u32_array_radix;
+///
+/// const SORTED_ARRAY: [u32; 4] = into_sorted_u32_array_radix([70_000, 3, u32::MAX, 256]);
+///
+/// assert_eq!(SORTED_ARRAY, [3, 256, 70_000, u32::MAX]);
+/// ```
+pub const fn into_sorted_u32_array_radix<const N: usize>(array: [u32; N]) -> [u32; N] {
+    radix_sort_u32(array)
+}
+
+/// Sorts the given array of `i32`s using the radix sort algorithm and returns it.
+///
+/// The elements are mapped to `u32`s that have the same order by flipping their sign bit,
+/// sorted with [`into_sorted_u32_array_radix`], and mapped back.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_i32_array_radix;
+///
+/// const SORTED_ARRAY: [i32; 5] = into_sorted_i32_array_radix([70_000, -3, i32::MAX, 0, i32::MIN]);
+///
+/// assert_eq!(SORTED_ARRAY, [i32::MIN, -3, 0, 70_000, i32::MAX]);
+/// ```
+pub const fn into_sorted_i32_array_radix<const N: usize>(mut array: [i32; N]) -> [i32; N] {
+    let mut biased = [0_u32; N];
+    let mut i = 0;
+    while i < N {
+        biased[i] = (array[i] as u32) ^ 0x8000_0000;
+        i += 1;
+    }
+
+    biased = radix_sort_u32(biased);
+
+    i = 0;
+    while i < N {
+        array[i] = (biased[i] ^ 0x8000_0000) as i32;
+        i += 1;
+    }
+    array
+}
+
+// endregion: counting sort implementations
+
+// region: stable sorting
+
+/// Defines `const` functions that compute the permutations that stably sort arrays of the given types
+/// using the merge sort algorithm.
+macro_rules! impl_const_stable_argsort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns the indices that stably sort the given array of `" $tpe "`s using the merge sort algorithm."]
+                #[doc = ""]
+                #[doc = "Element `i` of the returned array is the index in the input of the element"]
+                #[doc = "that ends up at position `i` when the array is sorted."]
+                #[doc = "Equal elements keep the order they have in the input, so the returned permutation can be used to"]
+                #[doc = "reorder parallel arrays of data associated with the elements while keeping equal keys in their original order."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<argsort_ $tpe _array_stable>] ";"]
+                #[doc = ""]
+                #[doc = "const KEYS: [" $tpe "; 4] = [1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "];"]
+                #[doc = "const TOKEN_IDS: [u32; 4] = [10, 11, 12, 13];"]
+                #[doc = ""]
+                #[doc = "const PERMUTATION: [usize; 4] = " [<argsort_ $tpe _array_stable>] "(&KEYS);"]
+                #[doc = ""]
+                #[doc = "const SORTED_TOKEN_IDS: [u32; 4] = {"]
+                #[doc = "    let mut ids = [0; 4];"]
+                #[doc = "    let mut i = 0;"]
+                #[doc = "    while i < ids.len() {"]
+                #[doc = "        ids[i] = TOKEN_IDS[PERMUTATION[i]];"]
+                #[doc = "        i += 1;"]
+                #[doc = "    }"]
+                #[doc = "    ids"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "assert_eq!(PERMUTATION, [1, 3, 0, 2]);"]
+                #[doc = "assert_eq!(SORTED_TOKEN_IDS, [11, 13, 10, 12]);"]
+                #[doc = "```"]
+                pub const fn [<argsort_ $tpe _array_stable>]<const N: usize>(array: &[$tpe; N]) -> [usize; N] {
+                    let mut indices = [0; N];
+                    let mut i = 0;
+                    while i < N {
+                        indices[i] = i;
+                        i += 1;
+                    }
+
+                    [<merge_sort_indices_by_ $tpe _keys>](indices, array, true, false)
+                }
+
+                /// Stably sorts the given indices by the keys they point to in ascending or descending order
+                /// using the bottom-up merge sort algorithm.
+                /// If `reverse_ties` is `true` indices with equal keys are put in the reverse of their input order instead.
+                const fn [<merge_sort_indices_by_ $tpe _keys>]<const N: usize>(mut indices: [usize; N], keys: &[$tpe], ascending: bool, reverse_ties: bool) -> [usize; N] {
+                    // Runs of length `width` are merged back and forth between `indices` and `buffer`.
+                    let mut buffer = [0; N];
+                    let mut width = 1;
+                    while width < N {
+                        let mut left = 0;
+                        while left < N {
+                            let mid = if N - left > width { left + width } else { N };
+                            let right = if N - mid > width { mid + width } else { N };
+
+                            let mut a = left;
+                            let mut b = mid;
+                            let mut k = left;
+                            while k < right {
+                                // Only take from the right run if its element strictly precedes the one in the left run,
+                                // this keeps equal elements in their original order.
+                                // Taking from the right run on ties as well, i.e. comparing with `<=` instead of `<`,
+                                // reverses the order of equal elements in every merge, and thus in the result.
+                                if b < right
+                                    && (a >= mid
+                                        || match (ascending, reverse_ties) {
+                                            (true, false) => [<less_than_ $tpe>](keys[indices[b]], keys[indices[a]]),
+                                            (true, true) => ![<less_than_ $tpe>](keys[indices[a]], keys[indices[b]]),
+                                            (false, false) => [<greater_than_ $tpe>](keys[indices[b]], keys[indices[a]]),
+                                            (false, true) => ![<greater_than_ $tpe>](keys[indices[a]], keys[indices[b]]),
+                                        })
+                                {
+                                    buffer[k] = indices[b];
+                                    b += 1;
+                                } else {
+                                    buffer[k] = indices[a];
+                                    a += 1;
+                                }
+                                k += 1;
+                            }
+
+                            left = right;
+                        }
+
+                        let temp = indices;
+                        indices = buffer;
+                        buffer = temp;
+                        width *= 2;
+                    }
+
+                    indices
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Stably sorts the given indices by the `" $tpe "`s they point to in `keys` and returns them."]
+                #[doc = ""]
+                #[doc = "The indices are sorted in ascending order of their keys if `out_ascending` is `true`,"]
+                #[doc = "and in descending order otherwise. In both cases indices with equal keys keep their relative order."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if any of the indices is out of bounds of `keys`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_indices_by_keys_ $tpe>] ";"]
+                #[doc = ""]
+                #[doc = "const KEYS: [" $tpe "; 5] = [3 as " $tpe ", 1 as " $tpe ", 3 as " $tpe ", 0 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const ASCENDING: [usize; 3] = " [<sort_indices_by_keys_ $tpe>] "([2, 0, 1], &KEYS, true);"]
+                #[doc = "const DESCENDING: [usize; 4] = " [<sort_indices_by_keys_ $tpe>] "([4, 0, 1, 2], &KEYS, false);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(ASCENDING, [1, 2, 0]);"]
+                #[doc = "assert_eq!(DESCENDING, [0, 2, 4, 1]);"]
+                #[doc = "```"]
+                pub const fn [<sort_indices_by_keys_ $tpe>]<const N: usize>(indices: [usize; N], keys: &[$tpe], out_ascending: bool) -> [usize; N] {
+                    let mut i = 0;
+                    while i < N {
+                        assert!(indices[i] < keys.len(), "an index is out of bounds of the keys");
+                        i += 1;
+                    }
+
+                    [<merge_sort_indices_by_ $tpe _keys>](indices, keys, out_ascending, false)
+                }
+
+                #[doc = "Returns the permutation that stably sorts the given array of `" $tpe "`s together with its inverse."]
+                #[doc = ""]
+                #[doc = "The first array is the same as the one returned by [`" [<argsort_ $tpe _array_stable>] "`]."]
+                #[doc = "The second array is its inverse, i.e. element `j` of it is the position in the sorted array"]
+                #[doc = "of element `j` of the input, such that `inverse[permutation[i]] == i`."]
+                #[doc = "It can be used to restore the original order of data that has been processed in sorted order."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<argsort_with_inverse_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const PERMUTATIONS: ([usize; 3], [usize; 3]) = " [<argsort_with_inverse_ $tpe _array>] "(&[2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(PERMUTATIONS, ([1, 2, 0], [2, 0, 1]));"]
+                #[doc = "```"]
+                pub const fn [<argsort_with_inverse_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> ([usize; N], [usize; N]) {
+                    let permutation = [<argsort_ $tpe _array_stable>](array);
+                    let mut inverse = [0; N];
+                    let mut i = 0;
+                    while i < N {
+                        inverse[permutation[i]] = i;
+                        i += 1;
+                    }
+                    (permutation, inverse)
+                }
+
+                #[doc = "Stably sorts the given array of `" $tpe "`s and returns it together with the original index of every sorted element."]
+                #[doc = ""]
+                #[doc = "Element `i` of the returned indices is the index in the input of the element at position `i` in the sorted array,"]
+                #[doc = "i.e. `array[indices[i]] == sorted[i]`. The indices are the same as the ones returned by"]
+                #[doc = "[`" [<argsort_ $tpe _array_stable>] "`], so equal elements are ordered by their original indices."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_indices_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED: ([" $tpe "; 4], [usize; 4]) = " [<into_sorted_with_indices_ $tpe _array>] "([2 as " $tpe ", 0 as " $tpe ", 2 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, ([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 2 as " $tpe "], [1, 3, 0, 2]));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_indices_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [usize; N]) {
+                    let indices = [<argsort_ $tpe _array_stable>](&array);
+                    let mut sorted = array;
+                    let mut i = 0;
+                    while i < N {
+                        sorted[i] = array[indices[i]];
+                        i += 1;
+                    }
+                    (sorted, indices)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s using the merge sort algorithm and returns it together with the original index of every sorted element,"]
+                #[doc = "with a choice of how to order equal elements."]
+                #[doc = ""]
+                #[doc = "If `reverse_ties` is `false` equal elements keep their original order, exactly like in [`" [<into_sorted_with_indices_ $tpe _array>] "`]."]
+                #[doc = "If it is `true` equal elements are placed in the reverse of their original order instead."]
+                #[doc = "This is done by comparing with `<=` instead of `<` in the merges."]
+                #[doc = ""]
+                #[doc = "Since equal `" $tpe "`s can not be told apart, the sorted array on its own would be the same in both modes."]
+                #[doc = "The original indices are therefore returned as well, since they are the only place where the order of ties is visible,"]
+                #[doc = "and they can be used to reorder associated data when composing sorts by several keys."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_stable_mode>] ";"]
+                #[doc = ""]
+                #[doc = "const STABLE: ([" $tpe "; 4], [usize; 4]) = " [<into_sorted_ $tpe _array_stable_mode>] "([1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "], false);"]
+                #[doc = "const REVERSE_STABLE: ([" $tpe "; 4], [usize; 4]) = " [<into_sorted_ $tpe _array_stable_mode>] "([1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "], true);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(STABLE, ([0 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "], [1, 3, 0, 2]));"]
+                #[doc = "assert_eq!(REVERSE_STABLE, ([0 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "], [3, 1, 2, 0]));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_stable_mode>]<const N: usize>(array: [$tpe; N], reverse_ties: bool) -> ([$tpe; N], [usize; N]) {
+                    let mut indices = [0; N];
+                    let mut i = 0;
+                    while i < N {
+                        indices[i] = i;
+                        i += 1;
+                    }
+                    indices = [<merge_sort_indices_by_ $tpe _keys>](indices, &array, true, reverse_ties);
+
+                    let mut sorted = array;
+                    i = 0;
+                    while i < N {
+                        sorted[i] = array[indices[i]];
+                        i += 1;
+                    }
+                    (sorted, indices)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_stable_argsort! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_stable_argsort! {f32, f64}
+
+#[rustversion::since(1.83.0)]
+/// Defines `const` functions that merge two sorted arrays of the given types into one sorted slice.
+macro_rules! impl_const_merge {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Merges the two given sorted arrays of `" $tpe "`s into `out` such that it is sorted."]
+                #[doc = ""]
+                #[doc = "The merge is stable: elements that are equal keep their relative order from the inputs,"]
+                #[doc = "and on equality the elements from `a` are placed before the elements from `b`."]
+                #[doc = "If the inputs are not sorted the order of the output is unspecified."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the length of `out` is not `A + B`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<merge_stable_ $tpe _arrays>] ";"]
+                #[doc = ""]
+                #[doc = "const MERGED: [" $tpe "; 5] = {"]
+                #[doc = "    let mut out = [0 as " $tpe "; 5];"]
+                #[doc = "    " [<merge_stable_ $tpe _arrays>] "([0 as " $tpe ", 2 as " $tpe ", 4 as " $tpe "], [1 as " $tpe ", 2 as " $tpe "], &mut out);"]
+                #[doc = "    out"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "assert_eq!(MERGED, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 2 as " $tpe ", 4 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<merge_stable_ $tpe _arrays>]<const A: usize, const B: usize>(a: [$tpe; A], b: [$tpe; B], out: &mut [$tpe]) {
+                    assert!(out.len() == A + B, "the length of the output must be the sum of the lengths of the inputs");
+
+                    let mut i = 0;
+                    let mut j = 0;
+                    let mut k = 0;
+                    while k < out.len() {
+                        // Only take from `b` if its element is strictly less, this places elements from `a` first on ties.
+                        if j < B && (i >= A || [<less_than_ $tpe>](b[j], a[i])) {
+                            out[k] = b[j];
+                            j += 1;
+                        } else {
+                            out[k] = a[i];
+                            i += 1;
+                        }
+                        k += 1;
+                    }
+                }
+
+                #[doc = "Merges the two given sorted arrays of `" $tpe "`s into the start of `out` while dropping duplicates, and returns the number of elements written."]
+                #[doc = ""]
+                #[doc = "Duplicates are dropped both within and across the inputs, so `out[..count]` is sorted and contains every distinct value of the inputs once."]
+                #[doc = "The elements of `out` after the returned count are left as they were."]
+                #[doc = "If the inputs are not sorted the output is unspecified."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the length of `out` is less than `A + B`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<merge_dedup_ $tpe _arrays>] ";"]
+                #[doc = ""]
+                #[doc = "const MERGED: ([" $tpe "; 5], usize) = {"]
+                #[doc = "    let mut out = [0 as " $tpe "; 5];"]
+                #[doc = "    let count = " [<merge_dedup_ $tpe _arrays>] "([0 as " $tpe ", 2 as " $tpe ", 4 as " $tpe "], [1 as " $tpe ", 2 as " $tpe "], &mut out);"]
+                #[doc = "    (out, count)"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "assert_eq!(MERGED.1, 4);"]
+                #[doc = "assert_eq!(MERGED.0[..MERGED.1], [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 4 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<merge_dedup_ $tpe _arrays>]<const A: usize, const B: usize>(a: [$tpe; A], b: [$tpe; B], out: &mut [$tpe]) -> usize {
+                    assert!(out.len() >= A + B, "the length of the output must be at least the sum of the lengths of the inputs");
+
+                    let mut i = 0;
+                    let mut j = 0;
+                    let mut count = 0;
+                    while i < A || j < B {
+                        let next = if j < B && (i >= A || [<less_than_ $tpe>](b[j], a[i])) {
+                            j += 1;
+                            b[j - 1]
+                        } else {
+                            i += 1;
+                            a[i - 1]
+                        };
+                        // The output is sorted, so `next` is a duplicate unless it is greater than the last written element.
+                        if count == 0 || [<less_than_ $tpe>](out[count - 1], next) {
+                            out[count] = next;
+                            count += 1;
+                        }
+                    }
+                    count
+                }
+            }
+        )+
+    };
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_merge! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize,
+    f32, f64
+}
+
+/// Sorts the given array of `char`s using the stable merge sort algorithm and returns it.
+///
+/// Since equal `char`s are bit-identical the result is the same as that of [`into_sorted_char_array`].
+/// Use [`argsort_char_array_stable`] to get the permutation that sorts the array if you need to keep
+/// data in parallel arrays in the same relative order for equal `char`s.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_char_array_stable;
+///
+/// const SORTED_ARRAY: [char; 4] = into_sorted_char_array_stable(['c', 'a', 'b', 'a']);
+///
+/// assert_eq!(SORTED_ARRAY, ['a', 'a', 'b', 'c']);
+/// ```
+pub const fn into_sorted_char_array_stable<const N: usize>(array: [char; N]) -> [char; N] {
+    let indices = argsort_char_array_stable(&array);
+
+    let mut sorted = array;
+    let mut i = 0;
+    while i < N {
+        sorted[i] = array[indices[i]];
+        i += 1;
+    }
+    sorted
+}
+
+/// Defines `const` functions that reorder arrays of the given types by a permutation.
+macro_rules! impl_const_apply_permutation {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Reorders the given array of `" $tpe "`s by the given permutation and returns it."]
+                #[doc = ""]
+                #[doc = "Element `i` of the output is `data[permutation[i]]`. Applying the permutation returned by e.g."]
+                #[doc = "[`" [<argsort_ $tpe _array_stable>] "`] sorts the array, and applying its inverse from"]
+                #[doc = "[`" [<argsort_with_inverse_ $tpe _array>] "`] restores the original order of the sorted data."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `permutation` is not a permutation of `0..N`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::{" [<apply_permutation_ $tpe _array>] ", " [<argsort_with_inverse_ $tpe _array>] "};"]
+                #[doc = ""]
+                #[doc = "const DATA: [" $tpe "; 3] = [2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "];"]
+                #[doc = "const PERMUTATIONS: ([usize; 3], [usize; 3]) = " [<argsort_with_inverse_ $tpe _array>] "(&DATA);"]
+                #[doc = "const SORTED: [" $tpe "; 3] = " [<apply_permutation_ $tpe _array>] "(DATA, PERMUTATIONS.0);"]
+                #[doc = "const UNSORTED: [" $tpe "; 3] = " [<apply_permutation_ $tpe _array>] "(SORTED, PERMUTATIONS.1);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "assert_eq!(UNSORTED, DATA);"]
+                #[doc = "```"]
+                pub const fn [<apply_permutation_ $tpe _array>]<const N: usize>(data: [$tpe; N], permutation: [usize; N]) -> [$tpe; N] {
+                    let mut seen = [false; N];
+                    let mut i = 0;
+                    while i < N {
+                        assert!(permutation[i] < N, "an index of the permutation is out of bounds");
+                        assert!(!seen[permutation[i]], "an index occurs more than once in the permutation");
+                        seen[permutation[i]] = true;
+                        i += 1;
+                    }
+
+                    let mut out = data;
+                    i = 0;
+                    while i < N {
+                        out[i] = data[permutation[i]];
+                        i += 1;
+                    }
+                    out
+                }
+            }
+        )+
+    };
+}
+
+impl_const_apply_permutation! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_apply_permutation! {f32, f64}
+
+// endregion: stable sorting
+
+// region: sorting by key
+
+// Closures and function pointers can not be called in `const` contexts, so sorting by a key is done with
+// a concrete function for every key. If the key is a bijection we decorate the elements by mapping them
+// to their keys, sort the keys with the regular sorting function, and then undecorate them by applying the inverse.
+
+/// Sorts the given array of `u32`s by their [`u32::swap_bytes`] using the introsort algorithm and returns it.
+///
+/// This sorts the values as if their byte order was reversed,
+/// e.g. it sorts big-endian values on a little-endian machine by their numerical value.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u32_array_by_swap_bytes;
+///
+/// const SORTED_ARRAY: [u32; 3] = into_sorted_u32_array_by_swap_bytes([0x0100_0000, 0x0000_0001, 0x0200_0000]);
+///
+/// assert_eq!(SORTED_ARRAY, [0x0100_0000, 0x0200_0000, 0x0000_0001]);
+/// ```
+pub const fn into_sorted_u32_array_by_swap_bytes<const N: usize>(mut array: [u32; N]) -> [u32; N] {
+    let mut i = 0;
+    while i < N {
+        array[i] = array[i].swap_bytes();
+        i += 1;
+    }
+
+    array = into_sorted_u32_array(array);
+
+    i = 0;
+    while i < N {
+        array[i] = array[i].swap_bytes();
+        i += 1;
+    }
+    array
+}
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given slice of `u32`s by their [`u32::swap_bytes`] using the introsort algorithm.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_u32_slice_by_swap_bytes;
+///
+/// const SORTED_ARRAY: [u32; 3] = {
+///     let mut arr = [0x0100_0000, 0x0000_0001, 0x0200_0000];
+///     sort_u32_slice_by_swap_bytes(&mut arr);
+///     arr
+/// };
+///
+/// assert_eq!(SORTED_ARRAY, [0x0100_0000, 0x0200_0000, 0x0000_0001]);
+/// ```
+pub const fn sort_u32_slice_by_swap_bytes(slice: &mut [u32]) {
+    let mut i = 0;
+    while i < slice.len() {
+        slice[i] = slice[i].swap_bytes();
+        i += 1;
+    }
+
+    sort_u32_slice(slice);
+
+    i = 0;
+    while i < slice.len() {
+        slice[i] = slice[i].swap_bytes();
+        i += 1;
+    }
+}
+
+/// Sorts the given array of `u32`s by their number of set bits, i.e. their [`u32::count_ones`],
+/// and values with the same number of set bits by their value, using the introsort algorithm and returns it.
+///
+/// This means that 0 sorts first, followed by the powers of two in increasing order.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_u32_array_by_popcount;
+///
+/// const SORTED_ARRAY: [u32; 5] = into_sorted_u32_array_by_popcount([3, 1, 7, 0, 2]);
+///
+/// assert_eq!(SORTED_ARRAY, [0, 1, 2, 3, 7]);
+/// ```
+pub const fn into_sorted_u32_array_by_popcount<const N: usize>(mut array: [u32; N]) -> [u32; N] {
+    // The number of set bits goes in the upper half of the keys, so they are ordered by it first and by the value second.
+    let mut keys = [0_u64; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = (array[i].count_ones() as u64) << u32::BITS | array[i] as u64;
+        i += 1;
+    }
+
+    keys = into_sorted_u64_array(keys);
+
+    i = 0;
+    while i < N {
+        array[i] = keys[i] as u32;
+        i += 1;
+    }
+    array
+}
+
+/// Sorts the given array of big-endian `u16`s stored as byte pairs, e.g. network ports, by their numeric value
+/// using the introsort algorithm and returns it.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_be_u16_bytes_array;
+///
+/// const SORTED_ARRAY: [[u8; 2]; 3] = into_sorted_be_u16_bytes_array([[1, 187], [255, 255], [0, 80]]);
+///
+/// assert_eq!(SORTED_ARRAY, [[0, 80], [1, 187], [255, 255]]);
+/// ```
+pub const fn into_sorted_be_u16_bytes_array<const N: usize>(
+    mut array: [[u8; 2]; N],
+) -> [[u8; 2]; N] {
+    let mut values = [0_u16; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = u16::from_be_bytes(array[i]);
+        i += 1;
+    }
+
+    values = into_sorted_u16_array(values);
+
+    i = 0;
+    while i < N {
+        array[i] = values[i].to_be_bytes();
+        i += 1;
+    }
+    array
+}
+
+#[rustversion::since(1.57.0)]
+/// Returns whether the bytes `key_start..key_end` of record `a` are lexicographically less than those of record `b`.
+const fn record_key_less_than<const L: usize>(
+    a: &[u8; L],
+    b: &[u8; L],
+    key_start: usize,
+    key_end: usize,
+) -> bool {
+    let mut i = key_start;
+    while i < key_end {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+#[rustversion::since(1.57.0)]
+/// Stably sorts the given array of fixed-size byte records by the key in bytes `key_start..key_start + key_len`
+/// of every record using the merge sort algorithm and returns it.
+///
+/// The keys are compared lexicographically, so multi-byte integer keys must be stored in big-endian byte order
+/// to be sorted by their numeric value. Records with equal keys keep their relative order,
+/// and the bytes outside the key move along with the rest of their record.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if the key range does not fit in the records, i.e. if `key_start + key_len` is greater than `L`.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_records_by_keyrange;
+///
+/// // Records of a one-byte tag followed by a big-endian `u16` id.
+/// const RECORDS: [[u8; 3]; 3] = [[b'a', 0, 2], [b'b', 1, 0], [b'c', 0, 1]];
+///
+/// const BY_ID: [[u8; 3]; 3] = into_sorted_records_by_keyrange(RECORDS, 1, 2);
+///
+/// assert_eq!(BY_ID, [[b'c', 0, 1], [b'a', 0, 2], [b'b', 1, 0]]);
+/// ```
+pub const fn into_sorted_records_by_keyrange<const L: usize, const N: usize>(
+    mut array: [[u8; L]; N],
+    key_start: usize,
+    key_len: usize,
+) -> [[u8; L]; N] {
+    assert!(
+        key_start <= L && key_len <= L - key_start,
+        "the key range does not fit in the records"
+    );
+    let key_end = key_start + key_len;
+
+    // Runs of length `width` are merged back and forth between `array` and `buffer`.
+    let mut buffer = array;
+    let mut width = 1;
+    while width < N {
+        let mut left = 0;
+        while left < N {
+            let mid = if N - left > width { left + width } else { N };
+            let right = if N - mid > width { mid + width } else { N };
+
+            let mut a = left;
+            let mut b = mid;
+            let mut k = left;
+            while k < right {
+                // Only take from the right run if its key strictly precedes the one in the left run,
+                // this keeps records with equal keys in their original order.
+                if b < right
+                    && (a >= mid || record_key_less_than(&array[b], &array[a], key_start, key_end))
+                {
+                    buffer[k] = array[b];
+                    b += 1;
+                } else {
+                    buffer[k] = array[a];
+                    a += 1;
+                }
+                k += 1;
+            }
+
+            left = right;
+        }
+
+        let temp = array;
+        array = buffer;
+        buffer = temp;
+        width *= 2;
+    }
+
+    array
+}
+
+/// Sorts the given array of `u16`s that contain the bits of IEEE 754 half-precision floats (f16)
+/// by the total order of the floats they represent using the introsort algorithm and returns it.
+///
+/// The order is the same as the one given by `total_cmp` for the other float types:
+/// negative NaNs, negative infinity, negative numbers, `-0.0`, `0.0`, positive numbers, positive infinity, and positive NaNs.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_f16_bits_array;
+///
+/// const ONE: u16 = 0x3C00;
+/// const NEGATIVE_ONE: u16 = 0xBC00;
+/// const NEGATIVE_ZERO: u16 = 0x8000;
+/// const NAN: u16 = 0x7E00;
+///
+/// const SORTED_ARRAY: [u16; 5] = into_sorted_f16_bits_array([NAN, ONE, 0, NEGATIVE_ZERO, NEGATIVE_ONE]);
+///
+/// assert_eq!(SORTED_ARRAY, [NEGATIVE_ONE, NEGATIVE_ZERO, 0, ONE, NAN]);
+/// ```
+pub const fn into_sorted_f16_bits_array<const N: usize>(mut array: [u16; N]) -> [u16; N] {
+    // Negative floats have all their bits flipped so that larger magnitudes sort first,
+    // while positive floats only have their sign bit flipped so that they sort after the negative ones.
+    let mut i = 0;
+    while i < N {
+        array[i] ^= if array[i] >> 15 == 1 {
+            u16::MAX
+        } else {
+            1 << 15
+        };
+        i += 1;
+    }
+
+    array = into_sorted_u16_array(array);
+
+    i = 0;
+    while i < N {
+        array[i] ^= if array[i] >> 15 == 1 {
+            1 << 15
+        } else {
+            u16::MAX
+        };
+        i += 1;
+    }
+    array
+}
+
+/// Sorts the given array of [`Wrapping<i32>`]s by their contained values using the introsort algorithm and returns it.
+///
+/// # Example
+///
+/// ```
+/// use core::num::Wrapping;
+/// use compile_time_sort::into_sorted_wrapping_i32_array;
+///
+/// const SORTED_ARRAY: [Wrapping<i32>; 3] = into_sorted_wrapping_i32_array([Wrapping(1), Wrapping(-1), Wrapping(0)]);
+///
+/// assert_eq!(SORTED_ARRAY, [Wrapping(-1), Wrapping(0), Wrapping(1)]);
+/// ```
+pub const fn into_sorted_wrapping_i32_array<const N: usize>(
+    mut array: [Wrapping<i32>; N],
+) -> [Wrapping<i32>; N] {
+    let mut values = [0_i32; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = array[i].0;
+        i += 1;
+    }
+
+    values = into_sorted_i32_array(values);
+
+    i = 0;
+    while i < N {
+        array[i] = Wrapping(values[i]);
+        i += 1;
+    }
+    array
+}
+
+/// Sorts the given array of [`Reverse<i32>`](core::cmp::Reverse)s using the introsort algorithm and returns it.
+///
+/// Since [`Reverse`](core::cmp::Reverse) inverts the order of the value it contains, this sorts the contained `i32`s
+/// in descending order. This is the idiomatic way to ask for a descending sort in the standard library,
+/// and it is equivalent to sorting the `i32`s with [`into_sorted_i32_array`] and reversing the result.
+///
+/// # Example
+///
+/// ```
+/// use core::cmp::Reverse;
+/// use compile_time_sort::into_sorted_reverse_i32_array;
+///
+/// const SORTED_ARRAY: [Reverse<i32>; 3] = into_sorted_reverse_i32_array([Reverse(1), Reverse(3), Reverse(2)]);
+///
+/// assert_eq!(SORTED_ARRAY, [Reverse(3), Reverse(2), Reverse(1)]);
+/// ```
+pub const fn into_sorted_reverse_i32_array<const N: usize>(
+    mut array: [core::cmp::Reverse<i32>; N],
+) -> [core::cmp::Reverse<i32>; N] {
+    let mut values = [0_i32; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = array[i].0;
+        i += 1;
+    }
+
+    values = into_sorted_i32_array(values);
+
+    i = 0;
+    while i < N {
+        array[i] = core::cmp::Reverse(values[N - 1 - i]);
+        i += 1;
+    }
+    array
+}
+
+/// A signed Q16.16 fixed-point number, i.e. an `i32` that represents the number `self.0 / 65536`.
+///
+/// Since the value is a two's complement integer scaled by a positive constant, the order of the raw `i32`s
+/// is the same as the numeric order of the fixed-point numbers they represent, including for negative numbers.
+/// This means that sorting them by their raw value sorts them numerically, see [`into_sorted_q16_16_array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Q16_16(pub i32);
+
+impl Q16_16 {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+
+    /// Returns the fixed-point number that is equal to the given integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use compile_time_sort::Q16_16;
+    ///
+    /// assert_eq!(Q16_16::from_int(-2), Q16_16(-2 << 16));
+    /// ```
+    pub const fn from_int(n: i16) -> Self {
+        Self((n as i32) << Self::FRAC_BITS)
+    }
+}
+
+/// Sorts the given array of [`Q16_16`] fixed-point numbers by their numeric value using the introsort algorithm and returns it.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::{into_sorted_q16_16_array, Q16_16};
+///
+/// const ONE_HALF: Q16_16 = Q16_16(1 << 15);
+/// const MINUS_ONE_AND_A_HALF: Q16_16 = Q16_16(-(3 << 15));
+///
+/// const SORTED_ARRAY: [Q16_16; 3] = into_sorted_q16_16_array([ONE_HALF, MINUS_ONE_AND_A_HALF, Q16_16::from_int(-1)]);
+///
+/// assert_eq!(SORTED_ARRAY, [MINUS_ONE_AND_A_HALF, Q16_16::from_int(-1), ONE_HALF]);
+/// ```
+pub const fn into_sorted_q16_16_array<const N: usize>(mut array: [Q16_16; N]) -> [Q16_16; N] {
+    let mut values = [0_i32; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = array[i].0;
+        i += 1;
+    }
+
+    values = into_sorted_i32_array(values);
+
+    i = 0;
+    while i < N {
+        array[i] = Q16_16(values[i]);
+        i += 1;
+    }
+    array
+}
+
+#[rustversion::since(1.74.0)]
+/// Sorts the given array of [`Saturating<i32>`](core::num::Saturating)s by their contained values
+/// using the introsort algorithm and returns it.
+///
+/// This function is only available on Rust versions 1.74 and above.
+///
+/// # Example
+///
+/// ```
+/// use core::num::Saturating;
+/// use compile_time_sort::into_sorted_saturating_i32_array;
+///
+/// const SORTED_ARRAY: [Saturating<i32>; 3] =
+///     into_sorted_saturating_i32_array([Saturating(1), Saturating(-1), Saturating(0)]);
+///
+/// assert_eq!(SORTED_ARRAY, [Saturating(-1), Saturating(0), Saturating(1)]);
+/// ```
+pub const fn into_sorted_saturating_i32_array<const N: usize>(
+    mut array: [core::num::Saturating<i32>; N],
+) -> [core::num::Saturating<i32>; N] {
+    let mut values = [0_i32; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = array[i].0;
+        i += 1;
+    }
+
+    values = into_sorted_i32_array(values);
+
+    i = 0;
+    while i < N {
+        array[i] = core::num::Saturating(values[i]);
+        i += 1;
+    }
+    array
+}
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given array of `f32`s by their absolute value using the introsort algorithm and returns it.
+///
+/// Elements with the same absolute value are ordered by [`f32::total_cmp`], so negative values come before
+/// positive values of the same magnitude. NaNs have a larger absolute value than every other value in this order,
+/// so they end up last.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_f32_array_by_abs;
+///
+/// const SORTED_ARRAY: [f32; 6] = into_sorted_f32_array_by_abs([-1.0, f32::NAN, 1.0, -0.5, 0.5, 0.0]);
+///
+/// assert_eq!(SORTED_ARRAY[..5], [0.0, -0.5, 0.5, -1.0, 1.0]);
+/// assert!(SORTED_ARRAY[5].is_nan());
+/// ```
+pub const fn into_sorted_f32_array_by_abs<const N: usize>(array: [f32; N]) -> [f32; N] {
+    // The key moves the sign bit to the least significant position and inverts it.
+    // This orders the keys by the absolute value of the float first, and by its sign second.
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        let bits = array[i].to_bits();
+        keys[i] = (bits << 1) | (!bits >> 31);
+        i += 1;
+    }
+
+    keys = into_sorted_u32_array(keys);
+
+    let mut sorted = array;
+    i = 0;
+    while i < N {
+        sorted[i] = f32::from_bits((keys[i] >> 1) | (!keys[i] << 31));
+        i += 1;
+    }
+    sorted
+}
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given array of `f64`s by their absolute value using the introsort algorithm and returns it.
+///
+/// Elements with the same absolute value are ordered by [`f64::total_cmp`], so negative values come before
+/// positive values of the same magnitude. NaNs have a larger absolute value than every other value in this order,
+/// so they end up last.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_f64_array_by_abs;
+///
+/// const SORTED_ARRAY: [f64; 6] = into_sorted_f64_array_by_abs([-1.0, f64::NAN, 1.0, -0.5, 0.5, 0.0]);
+///
+/// assert_eq!(SORTED_ARRAY[..5], [0.0, -0.5, 0.5, -1.0, 1.0]);
+/// assert!(SORTED_ARRAY[5].is_nan());
+/// ```
+pub const fn into_sorted_f64_array_by_abs<const N: usize>(array: [f64; N]) -> [f64; N] {
+    // The key moves the sign bit to the least significant position and inverts it.
+    // This orders the keys by the absolute value of the float first, and by its sign second.
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        let bits = array[i].to_bits();
+        keys[i] = (bits << 1) | (!bits >> 63);
+        i += 1;
+    }
+
+    keys = into_sorted_u64_array(keys);
+
+    let mut sorted = array;
+    i = 0;
+    while i < N {
+        sorted[i] = f64::from_bits((keys[i] >> 1) | (!keys[i] << 63));
+        i += 1;
+    }
+    sorted
+}
+
+/// Stably sorts the given array of packed RGBA colors by their perceived luminance using the merge sort algorithm and returns it.
+///
+/// The colors are assumed to be packed as `0xRRGGBBAA`, i.e. with the red channel in the most significant byte
+/// and the alpha channel in the least significant byte. The luminance is the fixed-point approximation
+/// `(77 * r + 150 * g + 29 * b) >> 8` of the BT.601 luma, and the alpha channel is ignored.
+/// Colors with the same luminance keep their relative order.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_rgba_by_luma_array;
+///
+/// const RED: u32 = 0xFF0000FF;
+/// const GREEN: u32 = 0x00FF00FF;
+/// const BLUE: u32 = 0x0000FFFF;
+///
+/// const SORTED_COLORS: [u32; 3] = into_sorted_rgba_by_luma_array([GREEN, RED, BLUE]);
+///
+/// assert_eq!(SORTED_COLORS, [BLUE, RED, GREEN]);
+/// ```
+pub const fn into_sorted_rgba_by_luma_array<const N: usize>(colors: [u32; N]) -> [u32; N] {
+    let mut lumas = [0_u32; N];
+    let mut i = 0;
+    while i < N {
+        let [r, g, b, _a] = colors[i].to_be_bytes();
+        lumas[i] = (77 * r as u32 + 150 * g as u32 + 29 * b as u32) >> 8;
+        i += 1;
+    }
+
+    let permutation = argsort_u32_array_stable(&lumas);
+
+    let mut sorted = colors;
+    i = 0;
+    while i < N {
+        sorted[i] = colors[permutation[i]];
+        i += 1;
+    }
+    sorted
+}
+
+/// Sorts the given array of `char`s in ASCII case-insensitive order and returns it.
+///
+/// The `char`s are compared by their [`to_ascii_lowercase`](char::to_ascii_lowercase), so e.g. `'B'` sorts after `'a'`.
+/// `char`s with the same lowercase form are ordered by their code point, which puts an uppercase ASCII letter
+/// right before its lowercase form. All other `char`s, including non-ASCII letters, are compared by their code points unchanged.
+///
+/// Note that uppercase ASCII letters sort after the punctuation between `'Z'` and `'a'`, such as `'_'`, since they sort as if they were lowercase.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_char_array_ascii_casefold;
+///
+/// const SORTED_ARRAY: [char; 6] = into_sorted_char_array_ascii_casefold(['b', 'Ä', 'B', 'a', '_', 'A']);
+///
+/// assert_eq!(SORTED_ARRAY, ['_', 'A', 'a', 'B', 'b', 'Ä']);
+/// ```
+pub const fn into_sorted_char_array_ascii_casefold<const N: usize>(chars: [char; N]) -> [char; N] {
+    // The keys are unique, since they contain the code point of the `char` in their lower half.
+    let mut keys = [0_u64; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = ((chars[i].to_ascii_lowercase() as u64) << 32) | chars[i] as u64;
+        i += 1;
+    }
+
+    let permutation = argsort_u64_array_stable(&keys);
+
+    let mut sorted = chars;
+    i = 0;
+    while i < N {
+        sorted[i] = chars[permutation[i]];
+        i += 1;
+    }
+    sorted
+}
+
+#[rustversion::since(1.57.0)]
+/// The number of bits used for each of the major, minor, and patch versions by [`pack_semver`].
+const SEMVER_FIELD_BITS: u32 = 10;
+
+#[rustversion::since(1.57.0)]
+/// Packs the given `major.minor.patch` version into a `u32`.
+///
+/// Every part of the version is stored in 10 bits, with the major version in bits 20 to 29,
+/// the minor version in bits 10 to 19, and the patch version in bits 0 to 9. The two most significant bits are zero.
+/// Since the more significant parts of the version are stored in the more significant bits,
+/// comparing two packed versions as `u32`s compares the versions by precedence.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if any part of the version is greater than 1023, i.e. does not fit in 10 bits.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::pack_semver;
+///
+/// const VERSION: u32 = pack_semver(1, 2, 3);
+///
+/// assert_eq!(VERSION, (1 << 20) | (2 << 10) | 3);
+/// assert!(pack_semver(1, 10, 0) > pack_semver(1, 9, 1023));
+/// ```
+pub const fn pack_semver(major: u16, minor: u16, patch: u16) -> u32 {
+    let max = (1 << SEMVER_FIELD_BITS) - 1;
+    assert!(major <= max, "the major version must fit in 10 bits");
+    assert!(minor <= max, "the minor version must fit in 10 bits");
+    assert!(patch <= max, "the patch version must fit in 10 bits");
+
+    ((major as u32) << (2 * SEMVER_FIELD_BITS))
+        | ((minor as u32) << SEMVER_FIELD_BITS)
+        | patch as u32
+}
+
+#[rustversion::since(1.57.0)]
+/// Sorts the given array of versions packed into `u32`s by [`pack_semver`] by their precedence and returns it.
+///
+/// Because of the layout of the packed versions this is the same as sorting them as `u32`s.
+///
+/// This function is only available on Rust versions 1.57 and above, like [`pack_semver`].
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::{into_sorted_semver_packed_array, pack_semver};
+///
+/// const VERSIONS: [u32; 3] = into_sorted_semver_packed_array([
+///     pack_semver(2, 0, 0),
+///     pack_semver(1, 2, 0),
+///     pack_semver(1, 0, 0),
+/// ]);
+///
+/// assert_eq!(VERSIONS, [pack_semver(1, 0, 0), pack_semver(1, 2, 0), pack_semver(2, 0, 0)]);
+/// ```
+pub const fn into_sorted_semver_packed_array<const N: usize>(versions: [u32; N]) -> [u32; N] {
+    into_sorted_u32_array(versions)
+}
+
+// endregion: sorting by key
+
+// region: sorting of 2D arrays
+
+/// Defines `const` functions that sort 2D arrays of the given types, either row by row or by the values in a column.
+macro_rules! impl_const_2d_sorts {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts every row of the given 2D array of `" $tpe "`s independently with [`" [<into_sorted_ $tpe _array>] "`] and returns it."]
+                #[doc = ""]
+                #[doc = "The rows stay in their original order."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_rows_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ROWS: [[" $tpe "; 3]; 2] = " [<sort_rows_ $tpe _array>] "(["]
+                #[doc = "    [2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "],"]
+                #[doc = "    [1 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "],"]
+                #[doc = "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ROWS, ["]
+                #[doc = "    [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "],"]
+                #[doc = "    [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "],"]
+                #[doc = "]);"]
+                #[doc = "```"]
+                pub const fn [<sort_rows_ $tpe _array>]<const R: usize, const C: usize>(mut array: [[$tpe; C]; R]) -> [[$tpe; C]; R] {
+                    let mut row = 0;
+                    while row < R {
+                        array[row] = [<into_sorted_ $tpe _array>](array[row]);
+                        row += 1;
+                    }
+                    array
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Stably sorts the rows of the given 2D array of `" $tpe "`s by their values in column `col` using the merge sort algorithm and returns it."]
+                #[doc = ""]
+                #[doc = "Whole rows are moved, and rows with equal values in the column keep their original relative order."]
+                #[doc = "This is like sorting a table of records by one of their fields."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `col` is not less than `C`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_rows_by_col_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const TABLE: [[" $tpe "; 2]; 3] = " [<sort_rows_by_col_ $tpe _array>] "(["]
+                #[doc = "    [0 as " $tpe ", 2 as " $tpe "],"]
+                #[doc = "    [1 as " $tpe ", 1 as " $tpe "],"]
+                #[doc = "    [2 as " $tpe ", 2 as " $tpe "],"]
+                #[doc = "], 1);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(TABLE, ["]
+                #[doc = "    [1 as " $tpe ", 1 as " $tpe "],"]
+                #[doc = "    [0 as " $tpe ", 2 as " $tpe "],"]
+                #[doc = "    [2 as " $tpe ", 2 as " $tpe "],"]
+                #[doc = "]);"]
+                #[doc = "```"]
+                pub const fn [<sort_rows_by_col_ $tpe _array>]<const R: usize, const C: usize>(array: [[$tpe; C]; R], col: usize) -> [[$tpe; C]; R] {
+                    assert!(col < C, "the column index must be less than the number of columns");
+
+                    if R == 0 {
+                        return array;
+                    }
+
+                    let mut keys = [array[0][col]; R];
+                    let mut row = 1;
+                    while row < R {
+                        keys[row] = array[row][col];
+                        row += 1;
+                    }
+
+                    let permutation = [<argsort_ $tpe _array_stable>](&keys);
+
+                    let mut sorted = array;
+                    row = 0;
+                    while row < R {
+                        sorted[row] = array[permutation[row]];
+                        row += 1;
+                    }
+                    sorted
+                }
+            }
+        )+
+    };
+}
+
+impl_const_2d_sorts! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_2d_sorts! {
+    f32, f64
+}
+
+// endregion: sorting of 2D arrays
+
+// region: sorting through references
+
+#[rustversion::since(1.83.0)]
+/// Defines `const` functions that sort arrays of the given types through references
+/// by delegating to the functions that sort slices.
+macro_rules! impl_const_array_ref_sorts {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Copies the given array of `" $tpe "`s into `output` and sorts it there, leaving `input` untouched."]
+                #[doc = ""]
+                #[doc = "This is useful when the input is borrowed, e.g. from a `static`."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<write_sorted_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "static INPUT: [" $tpe "; 3] = [1 as " $tpe ", 0 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = {"]
+                #[doc = "    let mut output = [0 as " $tpe "; 3];"]
+                #[doc = "    " [<write_sorted_ $tpe _array>] "(&[1 as " $tpe ", 0 as " $tpe ", 2 as " $tpe "], &mut output);"]
+                #[doc = "    output"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "let mut output = [0 as " $tpe "; 3];"]
+                #[doc = "" [<write_sorted_ $tpe _array>] "(&INPUT, &mut output);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "assert_eq!(output, SORTED_ARRAY);"]
+                #[doc = "```"]
+                pub const fn [<write_sorted_ $tpe _array>]<const N: usize>(input: &[$tpe; N], output: &mut [$tpe; N]) {
+                    *output = *input;
+                    [<sort_ $tpe _slice>](output);
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s in place through a mutable reference."]
+                #[doc = ""]
+                #[doc = "This is equivalent to calling [`" [<sort_ $tpe _slice>] "`] on the whole array, but avoids having to reslice it."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.83 and above."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_ $tpe _array_ref>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = {"]
+                #[doc = "    let mut arr = [3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = "    " [<sort_ $tpe _array_ref>] "(&mut arr);"]
+                #[doc = "    arr"]
+                #[doc = "};"]
+                #[doc = ""]
+                #[doc = "let mut arr = [3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = "" [<sort_ $tpe _array_ref>] "(&mut arr);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "assert_eq!(arr, SORTED_ARRAY);"]
+                #[doc = "```"]
+                pub const fn [<sort_ $tpe _array_ref>]<const N: usize>(array: &mut [$tpe; N]) {
+                    [<sort_ $tpe _slice>](array);
+                }
+            }
+        )+
+    };
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_array_ref_sorts! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize,
+    f32, f64
+}
+
+#[rustversion::since(1.83.0)]
+/// Copies the given array of `bool`s into `output` and sorts it there, leaving `input` untouched.
+///
+/// This is useful when the input is borrowed, e.g. from a `static`.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::write_sorted_bool_array;
+///
+/// static INPUT: [bool; 3] = [true, false, true];
+///
+/// let mut output = [false; 3];
+/// write_sorted_bool_array(&INPUT, &mut output);
+///
+/// assert_eq!(output, [false, true, true]);
+/// ```
+pub const fn write_sorted_bool_array<const N: usize>(input: &[bool; N], output: &mut [bool; N]) {
+    *output = *input;
+    sort_bool_slice(output);
+}
+
+#[rustversion::since(1.83.0)]
+/// Sorts the given array of `bool`s in place through a mutable reference.
+///
+/// This is equivalent to calling [`sort_bool_slice`] on the whole array, but avoids having to reslice it.
+///
+/// This function is only available on Rust versions 1.83 and above.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_bool_array_ref;
+///
+/// let mut arr = [true, false, true];
+/// sort_bool_array_ref(&mut arr);
+///
+/// assert_eq!(arr, [false, true, true]);
+/// ```
+pub const fn sort_bool_array_ref<const N: usize>(array: &mut [bool; N]) {
+    sort_bool_slice(array);
+}
+
+/// Defines `const` functions that copy the start of slices of the given types into arrays and sort them.
+macro_rules! impl_const_from_slice_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Copies the first `N` elements of the given slice of `" $tpe "`s into an array, sorts it with"]
+                #[doc = "[`" [<into_sorted_ $tpe _array>] "`], and returns it."]
+                #[doc = ""]
+                #[doc = "This is useful when the data is only available as a slice, e.g. from a `static`."]
+                #[doc = "The elements after the first `N` are ignored."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the slice has fewer than `N` elements. In a `const` context this is a compile error."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_from_slice>] ";"]
+                #[doc = ""]
+                #[doc = "static DATA: &[" $tpe "] = &[2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 3 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_from_slice>] "(&[2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "assert_eq!(" [<into_sorted_ $tpe _array_from_slice>] "::<3>(DATA), SORTED_ARRAY);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_from_slice>]<const N: usize>(slice: &[$tpe]) -> [$tpe; N] {
+                    assert!(slice.len() >= N, "the slice is shorter than the array");
+                    let mut array = [0 as $tpe; N];
+                    let mut i = 0;
+                    while i < N {
+                        array[i] = slice[i];
+                        i += 1;
+                    }
+                    [<into_sorted_ $tpe _array>](array)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_from_slice_sort! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_from_slice_sort! {f32, f64}
+
+#[rustversion::since(1.57.0)]
+/// Copies the first `N` elements of the given slice of `bool`s into an array, sorts it with
+/// [`into_sorted_bool_array`], and returns it.
+///
+/// This is useful when the data is only available as a slice, e.g. from a `static`.
+/// The elements after the first `N` are ignored.
+///
+/// This function is only available on Rust versions 1.57 and above.
+///
+/// # Panics
+///
+/// Panics if the slice has fewer than `N` elements. In a `const` context this is a compile error.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_bool_array_from_slice;
+///
+/// static DATA: &[bool] = &[true, false, true, false];
+///
+/// assert_eq!(into_sorted_bool_array_from_slice::<3>(DATA), [false, true, true]);
+/// ```
+pub const fn into_sorted_bool_array_from_slice<const N: usize>(slice: &[bool]) -> [bool; N] {
+    assert!(slice.len() >= N, "the slice is shorter than the array");
+    let mut array = [false; N];
+    let mut i = 0;
+    while i < N {
+        array[i] = slice[i];
+        i += 1;
+    }
+    into_sorted_bool_array(array)
+}
+
+// endregion: sorting through references
+
+// region: selection
+
+/// Defines a `const` function with the given name that rearranges the array such that the element at index `k`
+/// is the element that would be there if the array was sorted, all elements before it are less than or equal to it,
+/// and all elements after it are greater than or equal to it.
+/// It uses the quickselect algorithm and switches to heapsort if the array has been partitioned too many times.
+macro_rules! const_array_introselect {
+    ($tpe:ty, $name:ident, $partition_name:ident, $heap_name:ident) => {
+        const fn $name<const N: usize>(
+            mut array: [$tpe; N],
+            k: usize,
+            mut recursion_depth: u32,
+        ) -> [$tpe; N] {
+            let mut left = 0;
+            let mut right = N;
+            while right - left > 1 {
+                if recursion_depth == 0 {
+                    return $heap_name(array, left, right);
+                }
+
+                let (pivot_index, partitioned) = $partition_name(array, left, right);
+                array = partitioned;
+
+                if k == pivot_index {
+                    return array;
+                } else if k < pivot_index {
+                    right = pivot_index;
+                } else {
+                    left = pivot_index + 1;
+                }
+                recursion_depth -= 1;
+            }
+            array
+        }
+    };
+}
+
+/// Defines the public const selection functions for the given list of types.
+/// They reuse the partitioning and heapsort functions generated by `impl_const_introsort!`.
+macro_rules! impl_const_select {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                const_array_introselect!{$tpe, [<introselect_ $tpe _array>], [<partition_ $tpe _array>], [<heapsort_ $tpe _array>]}
+
+                #[doc = "Returns the `" $tpe "` that would be at index `k` if the given array was sorted,"]
+                #[doc = "or [`SortError::IndexOutOfRange`] if `k` is not a valid index into the array."]
+                #[doc = ""]
+                #[doc = "Uses the quickselect algorithm, so it does not need to sort the entire array."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::{" [<try_select_nth_ $tpe _array>] ", SortError};"]
+                #[doc = ""]
+                #[doc = "const ARRAY: [" $tpe "; 3] = [0 as " $tpe ", " $tpe "::MAX, " $tpe "::MIN];"]
+                #[doc = "const MIDDLE: Result<" $tpe ", SortError> = " [<try_select_nth_ $tpe _array>] "(ARRAY, 1);"]
+                #[doc = "const OUT_OF_RANGE: Result<" $tpe ", SortError> = " [<try_select_nth_ $tpe _array>] "(ARRAY, 3);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(MIDDLE, Ok(0 as " $tpe "));"]
+                #[doc = "assert_eq!(OUT_OF_RANGE, Err(SortError::IndexOutOfRange));"]
+                #[doc = "```"]
+                pub const fn [<try_select_nth_ $tpe _array>]<const N: usize>(array: [$tpe; N], k: usize) -> Result<$tpe, SortError> {
+                    match NonZeroUsize::new(N) {
+                        Some(nz) if k < N => Ok([<introselect_ $tpe _array>](array, k, 2*ilog2(nz))[k]),
+                        _ => Err(SortError::IndexOutOfRange),
+                    }
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Returns the `K` smallest `" $tpe "`s in the given array in ascending order."]
+                #[doc = ""]
+                #[doc = "Uses the quickselect algorithm to find the `K` smallest elements and then only sorts those."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `K` is larger than the length of the array."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<smallest_k_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SMALLEST: [" $tpe "; 2] = " [<smallest_k_ $tpe _array>] "([" $tpe "::MAX, 1 as " $tpe ", " $tpe "::MIN]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SMALLEST, [" $tpe "::MIN, 1 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<smallest_k_ $tpe _array>]<const N: usize, const K: usize>(array: [$tpe; N]) -> [$tpe; K] {
+                    assert!(K <= N, "`K` must not be larger than the length of the array");
+                    let mut smallest = [0 as $tpe; K];
+                    if let Some(nz) = NonZeroUsize::new(N) {
+                        if K > 0 {
+                            let array = [<introselect_ $tpe _array>](array, K - 1, 2*ilog2(nz));
+                            let mut i = 0;
+                            while i < K {
+                                smallest[i] = array[i];
+                                i += 1;
+                            }
+                        }
+                    }
+                    [<into_sorted_ $tpe _array>](smallest)
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Returns the `K` largest `" $tpe "`s in the given array in ascending order."]
+                #[doc = ""]
+                #[doc = "Uses the quickselect algorithm to find the `K` largest elements and then only sorts those."]
+                #[doc = "The result is the same as the last `K` elements of the sorted array."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `K` is larger than the length of the array."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<largest_k_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const LARGEST: [" $tpe "; 2] = " [<largest_k_ $tpe _array>] "([" $tpe "::MAX, 1 as " $tpe ", " $tpe "::MIN]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(LARGEST, [1 as " $tpe ", " $tpe "::MAX]);"]
+                #[doc = "```"]
+                pub const fn [<largest_k_ $tpe _array>]<const N: usize, const K: usize>(array: [$tpe; N]) -> [$tpe; K] {
+                    assert!(K <= N, "`K` must not be larger than the length of the array");
+                    let mut largest = [0 as $tpe; K];
+                    if let Some(nz) = NonZeroUsize::new(N) {
+                        if K > 0 {
+                            let array = [<introselect_ $tpe _array>](array, N - K, 2*ilog2(nz));
+                            let mut i = 0;
+                            while i < K {
+                                largest[i] = array[N - K + i];
+                                i += 1;
+                            }
+                        }
+                    }
+                    [<into_sorted_ $tpe _array>](largest)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_select! {
+    char,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_select! {f32, f64}
+
+/// Defines `const` functions that partition arrays of the given types around a given value.
+macro_rules! impl_const_partition_around_value {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Rearranges the given array of `" $tpe "`s such that all elements that are less than `threshold`"]
+                #[doc = "come before all elements that are greater than or equal to it, and returns it together with"]
+                #[doc = "the index of the first element that is not less than `threshold`."]
+                #[doc = ""]
+                #[doc = "The two groups are not sorted and the partition is not stable. This takes O(N) time,"]
+                #[doc = "which is cheaper than sorting the array if only the groups are needed."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<partition_around_value_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const PARTITIONED: ([" $tpe "; 5], usize) = " [<partition_around_value_ $tpe _array>] "([5 as " $tpe ", 1 as " $tpe ", 4 as " $tpe ", 0 as " $tpe ", 2 as " $tpe "], 2 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "let (array, split) = PARTITIONED;"]
+                #[doc = "assert_eq!(split, 2);"]
+                #[doc = "assert!(array[..split].iter().all(|&x| x < 2 as " $tpe "));"]
+                #[doc = "assert!(array[split..].iter().all(|&x| x >= 2 as " $tpe "));"]
+                #[doc = "```"]
+                pub const fn [<partition_around_value_ $tpe _array>]<const N: usize>(mut array: [$tpe; N], threshold: $tpe) -> ([$tpe; N], usize) {
+                    let mut split = 0;
+                    let mut i = 0;
+                    while i < N {
+                        if [<less_than_ $tpe>](array[i], threshold) {
+                            let temp = array[i];
+                            array[i] = array[split];
+                            array[split] = temp;
+                            split += 1;
+                        }
+                        i += 1;
+                    }
+                    (array, split)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_partition_around_value! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_partition_around_value! {f32, f64}
+
+/// Defines `const` functions that find the maximum of every window of a given length in arrays of the given types.
+macro_rules! impl_const_window_maxes {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Returns the maximum of every window of `W` consecutive elements in the given array of `" $tpe "`s,"]
+                #[doc = "together with the number of windows, `N - W + 1`."]
+                #[doc = ""]
+                #[doc = "Element `i` of the returned array is the maximum of `array[i..i + W]` for every `i` less than the number of windows."]
+                #[doc = "The remaining elements are unspecified. The maxima are found in O(N) time with a monotonic queue of indices."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `W` is 0 or greater than `N`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<window_maxes_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const MAXES: ([" $tpe "; 5], usize) = " [<window_maxes_ $tpe _array>] "::<5, 3>(&[1 as " $tpe ", 3 as " $tpe ", 2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(MAXES.1, 3);"]
+                #[doc = "assert_eq!(MAXES.0[..MAXES.1], [3 as " $tpe ", 3 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<window_maxes_ $tpe _array>]<const N: usize, const W: usize>(array: &[$tpe; N]) -> ([$tpe; N], usize) {
+                    assert!(W > 0, "the window length must be greater than 0");
+                    assert!(W <= N, "the window length must not be greater than the length of the array");
+
+                    let mut maxes = *array;
+                    // `queue[head..tail]` contains the indices of the elements of the current window that are greater than
+                    // all elements after them in the window, so the elements they point to are in descending order.
+                    let mut queue = [0_usize; N];
+                    let mut head = 0;
+                    let mut tail = 0;
+                    let mut i = 0;
+                    while i < N {
+                        while tail > head && ![<greater_than_ $tpe>](array[queue[tail - 1]], array[i]) {
+                            tail -= 1;
+                        }
+                        queue[tail] = i;
+                        tail += 1;
+
+                        if queue[head] + W <= i {
+                            head += 1;
+                        }
+                        if i + 1 >= W {
+                            maxes[i + 1 - W] = array[queue[head]];
+                        }
+                        i += 1;
+                    }
+                    (maxes, N - W + 1)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_window_maxes! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_window_maxes! {f32, f64}
+
+/// Defines `const` functions that find the two smallest and two largest elements of arrays of the given types.
+macro_rules! impl_const_extremes2 {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns the two smallest and the two largest elements of the given array of `" $tpe "`s"]
+                #[doc = "as `(min, second_min, second_max, max)`, or `None` if the array has fewer than two elements."]
+                #[doc = ""]
+                #[doc = "The result is the same as `(sorted[0], sorted[1], sorted[N - 2], sorted[N - 1])` for the sorted array,"]
+                #[doc = "but it is found in a single pass over the array without sorting it."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<extremes2_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const EXTREMES: Option<(" $tpe ", " $tpe ", " $tpe ", " $tpe ")> = " [<extremes2_ $tpe _array>] "(&[3 as " $tpe ", 0 as " $tpe ", 4 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(EXTREMES, Some((0 as " $tpe ", 1 as " $tpe ", 3 as " $tpe ", 4 as " $tpe ")));"]
+                #[doc = "assert_eq!(" [<extremes2_ $tpe _array>] "(&[1 as " $tpe "]), None);"]
+                #[doc = "```"]
+                pub const fn [<extremes2_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> Option<($tpe, $tpe, $tpe, $tpe)> {
+                    if N < 2 {
+                        return None;
+                    }
+
+                    let (mut min, mut second_min) = if [<less_than_ $tpe>](array[1], array[0]) {
+                        (array[1], array[0])
+                    } else {
+                        (array[0], array[1])
+                    };
+                    let (mut second_max, mut max) = (min, second_min);
+
+                    let mut i = 2;
+                    while i < N {
+                        let x = array[i];
+                        if [<less_than_ $tpe>](x, min) {
+                            second_min = min;
+                            min = x;
+                        } else if [<less_than_ $tpe>](x, second_min) {
+                            second_min = x;
+                        }
+                        if [<greater_than_ $tpe>](x, max) {
+                            second_max = max;
+                            max = x;
+                        } else if [<greater_than_ $tpe>](x, second_max) {
+                            second_max = x;
+                        }
+                        i += 1;
+                    }
+
+                    Some((min, second_min, second_max, max))
+                }
+            }
+        )+
+    };
+}
+
+impl_const_extremes2! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_extremes2! {f32, f64}
+
+/// Defines `const` functions that sort arrays of the given types and find the original indices of their extremes.
+macro_rules! impl_const_argmin_argmax {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts a copy of the given array of `" $tpe "`s and returns it together with the indices of the minimum"]
+                #[doc = "and maximum elements in the original array, as `(sorted, argmin, argmax)`, or `None` if the array is empty."]
+                #[doc = ""]
+                #[doc = "If several elements are equal to the minimum or maximum, the index of the first of them is returned."]
+                #[doc = "This makes it possible to look up the extremes in arrays that are parallel to the original one."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_argmin_argmax_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED: Option<([" $tpe "; 4], usize, usize)> = " [<into_sorted_with_argmin_argmax_ $tpe _array>] "(&[2 as " $tpe ", 3 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, Some(([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "], 2, 1)));"]
+                #[doc = "assert_eq!(" [<into_sorted_with_argmin_argmax_ $tpe _array>] "(&[]), None);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_argmin_argmax_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> Option<([$tpe; N], usize, usize)> {
+                    if N == 0 {
+                        return None;
+                    }
+
+                    let mut argmin = 0;
+                    let mut argmax = 0;
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](array[i], array[argmin]) {
+                            argmin = i;
+                        }
+                        if [<greater_than_ $tpe>](array[i], array[argmax]) {
+                            argmax = i;
+                        }
+                        i += 1;
+                    }
+
+                    Some(([<into_sorted_ $tpe _array>](*array), argmin, argmax))
+                }
+            }
+        )+
+    };
+}
+
+impl_const_argmin_argmax! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_argmin_argmax! {f32, f64}
+
+// endregion: selection
+
+// region: searching
+
+/// Defines `const` functions that query sorted arrays of the given types with binary search.
+macro_rules! impl_const_search {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                /// Returns the index of the first element in the sorted slice that is not less than `x`.
+                const fn [<lower_bound_ $tpe>](sorted: &[$tpe], x: $tpe) -> usize {
+                    let mut low = 0;
+                    let mut high = sorted.len();
+                    while low < high {
+                        let mid = low + (high - low) / 2;
+                        if [<less_than_ $tpe>](sorted[mid], x) {
+                            low = mid + 1;
+                        } else {
+                            high = mid;
+                        }
+                    }
+                    low
+                }
+
+                /// Returns the index of the first element in the sorted slice that is greater than `x`.
+                const fn [<upper_bound_ $tpe>](sorted: &[$tpe], x: $tpe) -> usize {
+                    let mut low = 0;
+                    let mut high = sorted.len();
+                    while low < high {
+                        let mid = low + (high - low) / 2;
+                        if [<less_or_equal_ $tpe>](sorted[mid], x) {
+                            low = mid + 1;
+                        } else {
+                            high = mid;
+                        }
+                    }
+                    low
+                }
+
+                #[doc = "Returns the number of elements in the given sorted array of `" $tpe "`s that are less than `x`"]
+                #[doc = "together with the number of elements that are equal to `x`."]
+                #[doc = ""]
+                #[doc = "The first number is the index `x` would be inserted at to keep the array sorted,"]
+                #[doc = "and together they give the rank of `x` in the array. Both are found with binary search."]
+                #[doc = "If the array is not sorted the result is unspecified."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<rank_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 5] = [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", " $tpe "::MAX];"]
+                #[doc = ""]
+                #[doc = "assert_eq!(" [<rank_ $tpe _array>] "(&SORTED_ARRAY, 1 as " $tpe "), (1, 3));"]
+                #[doc = "assert_eq!(" [<rank_ $tpe _array>] "(&SORTED_ARRAY, 2 as " $tpe "), (4, 0));"]
+                #[doc = "```"]
+                pub const fn [<rank_ $tpe _array>]<const N: usize>(sorted: &[$tpe; N], x: $tpe) -> (usize, usize) {
+                    let less = [<lower_bound_ $tpe>](sorted, x);
+                    (less, [<upper_bound_ $tpe>](sorted, x) - less)
+                }
+
+                #[doc = "Returns whether `x` is in the given sorted array of `" $tpe "`s."]
+                #[doc = ""]
+                #[doc = "The element is found with binary search. If the array is not sorted the result is unspecified."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sorted_contains_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const TABLE: [" $tpe "; 4] = [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", " $tpe "::MAX];"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(" [<sorted_contains_ $tpe _array>] "(&TABLE, 1 as " $tpe "));"]
+                #[doc = "const _: () = assert!(!" [<sorted_contains_ $tpe _array>] "(&TABLE, 2 as " $tpe "));"]
+                #[doc = "const _: () = assert!(!" [<sorted_contains_ $tpe _array>] "(&[], 0 as " $tpe "));"]
+                #[doc = "```"]
+                pub const fn [<sorted_contains_ $tpe _array>]<const N: usize>(sorted: &[$tpe; N], x: $tpe) -> bool {
+                    let index = [<lower_bound_ $tpe>](sorted, x);
+                    index < N && ![<less_than_ $tpe>](x, sorted[index])
+                }
+
+                #[doc = "Returns the index at which `x` should be inserted into the given sorted array of `" $tpe "`s"]
+                #[doc = "to keep it sorted, placing it after all elements that are equal to it."]
+                #[doc = ""]
+                #[doc = "This is the index to use for stable appends, where new values should come after the existing values that are equal to them."]
+                #[doc = "It is the number of elements that are less than or equal to `x`, and is found with binary search."]
+                #[doc = "If the array is not sorted the result is unspecified."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<insert_index_after_equal_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 5] = [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 5 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "assert_eq!(" [<insert_index_after_equal_ $tpe _array>] "(&SORTED_ARRAY, 1 as " $tpe "), 4);"]
+                #[doc = "assert_eq!(" [<insert_index_after_equal_ $tpe _array>] "(&SORTED_ARRAY, 2 as " $tpe "), 4);"]
+                #[doc = "assert_eq!(" [<insert_index_after_equal_ $tpe _array>] "(&SORTED_ARRAY, " $tpe "::MAX), 5);"]
+                #[doc = "```"]
+                pub const fn [<insert_index_after_equal_ $tpe _array>]<const N: usize>(sorted: &[$tpe; N], x: $tpe) -> usize {
+                    [<upper_bound_ $tpe>](sorted, x)
+                }
+
+                #[doc = "Returns the half-open range `(lo, hi)` of the indices of the elements that are equal to `x` in the given sorted array of `" $tpe "`s."]
+                #[doc = ""]
+                #[doc = "`lo` is the index of the first element that is not less than `x` and `hi` the index of the first element that is greater than `x`,"]
+                #[doc = "both found with binary search. If `x` is not in the array the range is empty and `lo == hi` is the index where it would be inserted."]
+                #[doc = "If the array is not sorted the result is unspecified."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<equal_range_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 5] = [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 5 as " $tpe "];"]
+                #[doc = "const RANGE: (usize, usize) = " [<equal_range_ $tpe _array>] "(&SORTED_ARRAY, 1 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "assert_eq!(RANGE, (1, 4));"]
+                #[doc = "assert_eq!(RANGE.1 - RANGE.0, 3);"]
+                #[doc = "assert_eq!(" [<equal_range_ $tpe _array>] "(&SORTED_ARRAY, 2 as " $tpe "), (4, 4));"]
+                #[doc = "```"]
+                pub const fn [<equal_range_ $tpe _array>]<const N: usize>(sorted: &[$tpe; N], x: $tpe) -> (usize, usize) {
+                    ([<lower_bound_ $tpe>](sorted, x), [<upper_bound_ $tpe>](sorted, x))
+                }
+            }
+        )+
+    };
+}
+
+impl_const_search! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_search! {f32, f64}
+
+/// Defines `const` functions that lay out arrays of the given types in Eytzinger order and search them.
+macro_rules! impl_const_eytzinger {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it reordered into the Eytzinger layout."]
+                #[doc = ""]
+                #[doc = "The Eytzinger layout stores a balanced binary search tree in breadth-first order, like a binary heap:"]
+                #[doc = "the root is at index 0, and the children of the node at index `k` are at the indices `2k + 1` and `2k + 2`."]
+                #[doc = "Every node is greater than or equal to the nodes in its left subtree and less than or equal to"]
+                #[doc = "the nodes in its right subtree. A search of the tree reads memory in a predictable pattern, which"]
+                #[doc = "makes it more cache and branch predictor friendly than a binary search of a sorted array."]
+                #[doc = "Search the returned array with [`" [<eytzinger_search_ $tpe _array>] "`]."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_eytzinger_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const TREE: [" $tpe "; 6] = " [<into_eytzinger_ $tpe _array>] "([5 as " $tpe ", 3 as " $tpe ", 0 as " $tpe ", 4 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(TREE, [3 as " $tpe ", 1 as " $tpe ", 5 as " $tpe ", 0 as " $tpe ", 2 as " $tpe ", 4 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_eytzinger_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut tree = sorted;
+
+                    // Visit the nodes of the tree in order and give them the sorted elements in turn.
+                    // The nodes are 1-indexed here, so the children of node `k` are `2k` and `2k + 1`.
+                    let mut k = 1;
+                    while 2 * k <= N {
+                        k *= 2;
+                    }
+                    let mut i = 0;
+                    while i < N {
+                        tree[k - 1] = sorted[i];
+                        if 2 * k < N {
+                            // Go to the leftmost node of the right subtree.
+                            k = 2 * k + 1;
+                            while 2 * k <= N {
+                                k *= 2;
+                            }
+                        } else {
+                            // Go up until we come from a left child.
+                            while k % 2 == 1 {
+                                k /= 2;
+                            }
+                            k /= 2;
+                        }
+                        i += 1;
+                    }
+                    tree
+                }
+
+                #[doc = "Searches the given array of `" $tpe "`s in the Eytzinger layout for `x`."]
+                #[doc = ""]
+                #[doc = "Returns the index of an element equal to `x` in the given array, or `None` if there is none."]
+                #[doc = "The array must be in the layout returned by [`" [<into_eytzinger_ $tpe _array>] "`], otherwise the result is unspecified."]
+                #[doc = "The search takes O(log N) time."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::{" [<eytzinger_search_ $tpe _array>] ", " [<into_eytzinger_ $tpe _array>] "};"]
+                #[doc = ""]
+                #[doc = "const TREE: [" $tpe "; 4] = " [<into_eytzinger_ $tpe _array>] "([6 as " $tpe ", 2 as " $tpe ", 0 as " $tpe ", 4 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "const INDEX: Option<usize> = " [<eytzinger_search_ $tpe _array>] "(&TREE, 4 as " $tpe ");"]
+                #[doc = "assert_eq!(TREE[INDEX.unwrap()], 4 as " $tpe ");"]
+                #[doc = "assert_eq!(" [<eytzinger_search_ $tpe _array>] "(&TREE, 3 as " $tpe "), None);"]
+                #[doc = "```"]
+                pub const fn [<eytzinger_search_ $tpe _array>]<const N: usize>(tree: &[$tpe; N], x: $tpe) -> Option<usize> {
+                    let mut k = 0;
+                    while k < N {
+                        if [<less_than_ $tpe>](tree[k], x) {
+                            k = 2 * k + 2;
+                        } else if [<greater_than_ $tpe>](tree[k], x) {
+                            k = 2 * k + 1;
+                        } else {
+                            return Some(k);
+                        }
+                    }
+                    None
+                }
+            }
+        )+
+    };
+}
+
+impl_const_eytzinger! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_eytzinger! {f32, f64}
+
+/// Defines `const` functions that sort arrays of the given signed integer types and find where the signs change.
+macro_rules! impl_const_sign_splits {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the index of its first non-negative element"]
+                #[doc = "and the index of its first positive element."]
+                #[doc = ""]
+                #[doc = "The negative elements are in `..non_negative`, the zeros in `non_negative..positive`,"]
+                #[doc = "and the positive elements in `positive..`. The indices are found with binary search after sorting."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_sign_splits_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SPLIT: ([" $tpe "; 5], usize, usize) = " [<into_sorted_with_sign_splits_ $tpe _array>] "([2, 0, -1, " $tpe "::MIN, 0]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SPLIT, ([" $tpe "::MIN, -1, 0, 0, 2], 2, 4));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_sign_splits_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], usize, usize) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let non_negative = [<lower_bound_ $tpe>](&sorted, 0);
+                    let positive = [<lower_bound_ $tpe>](&sorted, 1);
+                    (sorted, non_negative, positive)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s into two segments and returns it: first the non-negative elements in ascending order,"]
+                #[doc = "and then the negative elements in order of increasing magnitude, i.e. in descending order."]
+                #[doc = ""]
+                #[doc = "For example the elements `-3..=3` end up as `[0, 1, 2, 3, -1, -2, -3]`. Zeros belong to the first segment."]
+                #[doc = "The segments are found with binary search after sorting, and the negative segment is copied in reverse."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_magnitude_segmented>] ";"]
+                #[doc = ""]
+                #[doc = "const SEGMENTED: [" $tpe "; 6] = " [<into_sorted_ $tpe _array_magnitude_segmented>] "([-1, 2, -3, 0, 1, " $tpe "::MIN]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SEGMENTED, [0, 1, 2, -1, -3, " $tpe "::MIN]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_magnitude_segmented>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let negatives = [<lower_bound_ $tpe>](&sorted, 0);
+
+                    let mut segmented = sorted;
+                    let mut i = 0;
+                    while i < N - negatives {
+                        segmented[i] = sorted[negatives + i];
+                        i += 1;
+                    }
+                    // The negative elements are ascending in `sorted`, so copying them backwards orders them by magnitude.
+                    i = 0;
+                    while i < negatives {
+                        segmented[N - negatives + i] = sorted[negatives - 1 - i];
+                        i += 1;
+                    }
+                    segmented
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sign_splits! {
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+}
+
+/// Defines `const` functions that sort arrays of the given types and count the elements below, equal to, and above a threshold,
+/// or only the elements equal to a pivot.
+macro_rules! impl_const_ternary_counts {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the number of elements"]
+                #[doc = "that are less than, equal to, and greater than `threshold`, in that order."]
+                #[doc = ""]
+                #[doc = "The counts are found with binary search after sorting, so the elements less than `threshold`"]
+                #[doc = "are in `..lt`, the ones equal to it in `lt..lt + eq`, and the greater ones in `lt + eq..`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sorted_with_ternary_counts_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const COUNTS: ([" $tpe "; 5], usize, usize, usize) = " [<sorted_with_ternary_counts_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 2 as " $tpe ", 0 as " $tpe "], 2 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "assert_eq!(COUNTS, ([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "], 2, 2, 1));"]
+                #[doc = "```"]
+                pub const fn [<sorted_with_ternary_counts_ $tpe _array>]<const N: usize>(array: [$tpe; N], threshold: $tpe) -> ([$tpe; N], usize, usize, usize) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let lt = [<lower_bound_ $tpe>](&sorted, threshold);
+                    let le = [<upper_bound_ $tpe>](&sorted, threshold);
+                    (sorted, lt, le - lt, N - le)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the number of elements that are equal to `pivot`."]
+                #[doc = ""]
+                #[doc = "The count is the length of the [`" [<equal_range_ $tpe _array>] "`] of `pivot` in the sorted array,"]
+                #[doc = "so it is found with binary search in O(log N) time after sorting."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_count_equal_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED: ([" $tpe "; 6], usize) = " [<into_sorted_count_equal_ $tpe _array>] "([2 as " $tpe ", 3 as " $tpe ", 2 as " $tpe ", 0 as " $tpe ", 2 as " $tpe ", 1 as " $tpe "], 2 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, ([0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 2 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "], 3));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_count_equal_ $tpe _array>]<const N: usize>(array: [$tpe; N], pivot: $tpe) -> ([$tpe; N], usize) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let (start, end) = [<equal_range_ $tpe _array>](&sorted, pivot);
+                    (sorted, end - start)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_ternary_counts! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_ternary_counts! {
+    f32, f64
+}
+
+/// Defines `const` functions that sort arrays of the given types and find where the elements equal to a sentinel begin.
+macro_rules! impl_const_sentinel_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the number of elements"]
+                #[doc = "that are less than `sentinel`."]
+                #[doc = ""]
+                #[doc = "This is meant for tables where a sentinel such as `" $tpe "::MAX` marks empty slots,"]
+                #[doc = "and assumes that the sentinel is the maximum value of the array."]
+                #[doc = "The sentinels are then sorted to the end, and the returned index is where they begin,"]
+                #[doc = "i.e. the number of elements that are not sentinels."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_until_sentinel_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const TABLE: ([" $tpe "; 5], usize) = " [<into_sorted_until_sentinel_ $tpe _array>] "([3, " $tpe "::MAX, 1, " $tpe "::MAX, 2], " $tpe "::MAX);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(TABLE, ([1, 2, 3, " $tpe "::MAX, " $tpe "::MAX], 3));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_until_sentinel_ $tpe _array>]<const N: usize>(array: [$tpe; N], sentinel: $tpe) -> ([$tpe; N], usize) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let end = [<lower_bound_ $tpe>](&sorted, sentinel);
+                    (sorted, end)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sentinel_sort! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// Defines `const` functions that sort arrays of the given types and return the index that splits them into two halves.
+macro_rules! impl_const_sorted_halves {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the index `N / 2` that splits it into two halves."]
+                #[doc = ""]
+                #[doc = "`sorted[..mid]` and `sorted[mid..]` are then sorted halves whose lengths differ by at most one."]
+                #[doc = "If `N` is odd the second half is the longer one, and its first element is the median."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_halves_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const HALVES: ([" $tpe "; 5], usize) = " [<into_sorted_halves_ $tpe _array>] "([3 as " $tpe ", 0 as " $tpe ", 4 as " $tpe ", 2 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = "let (sorted, mid) = HALVES;"]
+                #[doc = ""]
+                #[doc = "assert_eq!(sorted[..mid], [0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = "assert_eq!(sorted[mid..], [2 as " $tpe ", 3 as " $tpe ", 4 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_halves_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], usize) {
+                    ([<into_sorted_ $tpe _array>](array), N / 2)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_halves! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sorted_halves! {
+    f32, f64
+}
+
+/// Defines `const` functions that find the length of the sorted prefix of arrays of the given types,
+/// the index where they stop being sorted, and whether they are strictly sorted.
+macro_rules! impl_const_sorted_prefix_len {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns the length of the longest prefix of the given array of `" $tpe "`s that is sorted,"]
+                #[doc = "i.e. the largest `k` such that `array[..k]` is non-decreasing."]
+                #[doc = ""]
+                #[doc = "This is `N` if the whole array is sorted, and 0 only if the array is empty."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sorted_prefix_len_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const PREFIX_LEN: usize = " [<sorted_prefix_len_ $tpe _array>] "(&[0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(PREFIX_LEN, 3);"]
+                #[doc = "assert_eq!(" [<sorted_prefix_len_ $tpe _array>] "(&[1 as " $tpe ", 0 as " $tpe "]), 1);"]
+                #[doc = "assert_eq!(" [<sorted_prefix_len_ $tpe _array>] "(&[0 as " $tpe ", 1 as " $tpe "]), 2);"]
+                #[doc = "assert_eq!(" [<sorted_prefix_len_ $tpe _array>] "(&[]), 0);"]
+                #[doc = "```"]
+                pub const fn [<sorted_prefix_len_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> usize {
+                    if N == 0 {
+                        return 0;
+                    }
+                    let mut k = 1;
+                    while k < N && ![<greater_than_ $tpe>](array[k - 1], array[k]) {
+                        k += 1;
+                    }
+                    k
+                }
+
+                #[doc = "Returns the first index `i` such that `array[i - 1] > array[i]` in the given array of `" $tpe "`s, or `None` if the array is sorted."]
+                #[doc = ""]
+                #[doc = "This is useful for reporting where generated data stops being sorted without panicking."]
+                #[doc = "Empty arrays and arrays with a single element are sorted."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<first_unsorted_index_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const UNSORTED: Option<usize> = " [<first_unsorted_index_ $tpe _array>] "(&[0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 0 as " $tpe "]);"]
+                #[doc = "const SORTED: Option<usize> = " [<first_unsorted_index_ $tpe _array>] "(&[0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(UNSORTED, Some(3));"]
+                #[doc = "assert_eq!(SORTED, None);"]
+                #[doc = "```"]
+                pub const fn [<first_unsorted_index_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> Option<usize> {
+                    let prefix_len = [<sorted_prefix_len_ $tpe _array>](array);
+                    if prefix_len < N {
+                        Some(prefix_len)
+                    } else {
+                        None
+                    }
+                }
+
+                #[doc = "Returns whether the given array of `" $tpe "`s is strictly sorted, i.e. whether every element is less than the next."]
+                #[doc = ""]
+                #[doc = "Unlike a sorted array, a strictly sorted array can not contain equal neighbors, so this also checks that"]
+                #[doc = "a sorted table has no duplicate keys. Empty arrays and arrays with a single element are strictly sorted."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<is_strictly_sorted_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const ARRAY: [" $tpe "; 3] = [1 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "assert!(ARRAY.is_sorted());"]
+                #[doc = "assert!(!" [<is_strictly_sorted_ $tpe _array>] "(&ARRAY));"]
+                #[doc = "assert!(" [<is_strictly_sorted_ $tpe _array>] "(&[1 as " $tpe ", 2 as " $tpe "]));"]
+                #[doc = "```"]
+                pub const fn [<is_strictly_sorted_ $tpe _array>]<const N: usize>(array: &[$tpe; N]) -> bool {
+                    let mut i = 1;
+                    while i < N {
+                        if ![<less_than_ $tpe>](array[i - 1], array[i]) {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_prefix_len! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sorted_prefix_len! {f32, f64}
+
+/// Returns the length of the longest prefix of the given array of `bool`s that is sorted,
+/// i.e. the largest `k` such that `array[..k]` is non-decreasing.
+///
+/// This is `N` if the whole array is sorted, and 0 only if the array is empty.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sorted_prefix_len_bool_array;
+///
+/// const PREFIX_LEN: usize = sorted_prefix_len_bool_array(&[false, true, true, false]);
+///
+/// assert_eq!(PREFIX_LEN, 3);
+/// ```
+pub const fn sorted_prefix_len_bool_array<const N: usize>(array: &[bool; N]) -> usize {
+    if N == 0 {
+        return 0;
+    }
+    let mut k = 1;
+    while k < N && !greater_than_bool(array[k - 1], array[k]) {
+        k += 1;
+    }
+    k
+}
+
+// endregion: searching
+
+// region: multiset queries
+
+/// Defines `const` functions that compute properties of the multiset of elements in an array
+/// by sorting a copy of it with the sorting functions generated by `impl_const_introsort!`.
+macro_rules! impl_const_multiset_queries {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns the number of distinct `" $tpe "`s in the given array."]
+                #[doc = ""]
+                #[doc = "Sorts a copy of the array and counts the number of adjacent elements that differ."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<distinct_count_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const DISTINCT: usize = " [<distinct_count_ $tpe _array>] "([1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(DISTINCT, 2);"]
+                #[doc = "```"]
+                pub const fn [<distinct_count_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> usize {
+                    if N == 0 {
+                        return 0;
+                    }
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut count = 1;
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](sorted[i - 1], sorted[i]) {
+                            count += 1;
+                        }
+                        i += 1;
+                    }
+                    count
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the length of its longest run of equal elements."]
+                #[doc = ""]
+                #[doc = "The length of the longest run is the number of times the most frequent value occurs."]
+                #[doc = "It is 0 for an empty array."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_max_run_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED: ([" $tpe "; 4], usize) = " [<into_sorted_with_max_run_ $tpe _array>] "([1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED, ([0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "], 3));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_max_run_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], usize) {
+                    if N == 0 {
+                        return (array, 0);
+                    }
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut max_run = 1;
+                    let mut run = 1;
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](sorted[i - 1], sorted[i]) {
+                            run = 1;
+                        } else {
+                            run += 1;
+                            if run > max_run {
+                                max_run = run;
+                            }
+                        }
+                        i += 1;
+                    }
+                    (sorted, max_run)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_multiset_queries! {
+    char,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_multiset_queries! {f32, f64}
+
+/// Defines `const` functions that sort arrays of the given types and remove or detect the duplicates in them.
+macro_rules! impl_const_sorted_dedup {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s, moves its distinct values to the front, and returns it together with the number of distinct values."]
+                #[doc = ""]
+                #[doc = "If the returned count is `k`, then `array[..k]` is sorted and contains every distinct value of the input once."]
+                #[doc = "The order of the elements in `array[k..]` is unspecified."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_dedup_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const DEDUPED: ([" $tpe "; 5], usize) = " [<into_sorted_dedup_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 3 as " $tpe ", 2 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(DEDUPED.1, 3);"]
+                #[doc = "assert_eq!(DEDUPED.0[..DEDUPED.1], [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_dedup_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], usize) {
+                    if N == 0 {
+                        return (array, 0);
+                    }
+                    let mut sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut count = 1;
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](sorted[count - 1], sorted[i]) {
+                            let temp = sorted[count];
+                            sorted[count] = sorted[i];
+                            sorted[i] = temp;
+                            count += 1;
+                        }
+                        i += 1;
+                    }
+                    (sorted, count)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with whether all of its elements are distinct."]
+                #[doc = ""]
+                #[doc = "The elements are distinct if no two adjacent elements of the sorted array are equal."]
+                #[doc = "Empty arrays and arrays with a single element are always distinct."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_distinct_flag_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const IDS: ([" $tpe "; 3], bool) = " [<into_sorted_distinct_flag_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "const DUPLICATE_IDS: ([" $tpe "; 3], bool) = " [<into_sorted_distinct_flag_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(IDS, ([1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "], true));"]
+                #[doc = "assert_eq!(DUPLICATE_IDS, ([1 as " $tpe ", 3 as " $tpe ", 3 as " $tpe "], false));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_distinct_flag_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], bool) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut i = 1;
+                    while i < N {
+                        if ![<less_than_ $tpe>](sorted[i - 1], sorted[i]) {
+                            return (sorted, false);
+                        }
+                        i += 1;
+                    }
+                    (sorted, true)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with a mask that marks its duplicates."]
+                #[doc = ""]
+                #[doc = "Entry `i` of the mask is `true` if element `i` of the sorted array is equal to element `i - 1`."]
+                #[doc = "The first element is never marked, so the unmarked elements are the distinct values of the array in ascending order."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_dup_mask_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const MASKED: ([" $tpe "; 4], [bool; 4]) = " [<into_sorted_dup_mask_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 3 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(MASKED.0, [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "assert_eq!(MASKED.1, [false, false, false, true]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_dup_mask_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [bool; N]) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut duplicates = [false; N];
+                    let mut i = 1;
+                    while i < N {
+                        duplicates[i] = ![<less_than_ $tpe>](sorted[i - 1], sorted[i]);
+                        i += 1;
+                    }
+                    (sorted, duplicates)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the indices where its runs of equal elements start"]
+                #[doc = "and the number of runs."]
+                #[doc = ""]
+                #[doc = "If the returned count is `k`, then the first `k` indices are the starts of the runs in ascending order,"]
+                #[doc = "so run `i` is `sorted[starts[i]..starts[i + 1]]`, and the last run ends at `N`. The remaining indices are 0."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_run_starts_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const RUNS: ([" $tpe "; 5], [usize; 5], usize) = " [<into_sorted_run_starts_ $tpe _array>] "([3 as " $tpe ", 1 as " $tpe ", 3 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(RUNS.0, [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe ", 3 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "assert_eq!(RUNS.1[..RUNS.2], [0, 1, 2]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_run_starts_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [usize; N], usize) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut starts = [0; N];
+                    if N == 0 {
+                        return (sorted, starts, 0);
+                    }
+
+                    let mut count = 1;
+                    let mut i = 1;
+                    while i < N {
+                        if [<less_than_ $tpe>](sorted[i - 1], sorted[i]) {
+                            starts[count] = i;
+                            count += 1;
+                        }
+                        i += 1;
+                    }
+                    (sorted, starts, count)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_dedup! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sorted_dedup! {f32, f64}
+
+/// Defines `const` functions that check whether two arrays of the given types sort to the same array,
+/// or whether an array sorts to an expected array.
+macro_rules! impl_const_sort_equal {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns whether the two given arrays of `" $tpe "`s are equal after they have been sorted."]
+                #[doc = ""]
+                #[doc = "In other words, whether they contain the same elements the same number of times."]
+                #[doc = "This is useful in `const` assertions."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sort_equal_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const GENERATED: [" $tpe "; 3] = [1 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "];"]
+                #[doc = "const EXPECTED: [" $tpe "; 3] = [0 as " $tpe ", 1 as " $tpe ", 1 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(" [<sort_equal_ $tpe _array>] "(GENERATED, EXPECTED));"]
+                #[doc = "const _: () = assert!(!" [<sort_equal_ $tpe _array>] "(GENERATED, [1 as " $tpe "; 3]));"]
+                #[doc = "```"]
+                pub const fn [<sort_equal_ $tpe _array>]<const N: usize>(a: [$tpe; N], b: [$tpe; N]) -> bool {
+                    let a = [<into_sorted_ $tpe _array>](a);
+                    let b = [<into_sorted_ $tpe _array>](b);
+                    let mut i = 0;
+                    while i < N {
+                        if [<less_than_ $tpe>](a[i], b[i]) || [<greater_than_ $tpe>](a[i], b[i]) {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                #[doc = "Returns whether sorting the given array of `" $tpe "`s results in exactly the `expected` array."]
+                #[doc = ""]
+                #[doc = "Unlike [`" [<sort_equal_ $tpe _array>] "`] the expected array is not sorted, so this can lock"]
+                #[doc = "the sorted contents of a table to a golden array in a `const` assertion."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_equals_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const INPUT: [" $tpe "; 3] = [2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "];"]
+                #[doc = "const GOLDEN: [" $tpe "; 3] = [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(" [<into_sorted_equals_ $tpe _array>] "(INPUT, &GOLDEN));"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "An expected array that contains the same elements in another order does not match:"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_equals_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const INPUT: [" $tpe "; 3] = [2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(!" [<into_sorted_equals_ $tpe _array>] "(INPUT, &INPUT));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_equals_ $tpe _array>]<const N: usize>(array: [$tpe; N], expected: &[$tpe; N]) -> bool {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut i = 0;
+                    while i < N {
+                        if [<less_than_ $tpe>](sorted[i], expected[i]) || [<greater_than_ $tpe>](sorted[i], expected[i]) {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sort_equal! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sort_equal! {f32, f64}
+
+/// Defines `const` functions that check whether a sorted array of the given types is a subset of another.
+macro_rules! impl_const_sorted_subset {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns whether every element of the sorted array `sub` of `" $tpe "`s also occurs in the sorted array `sup`."]
+                #[doc = ""]
+                #[doc = "This is a set subset check that ignores multiplicity, so an element that occurs several times in `sub`"]
+                #[doc = "only needs to occur once in `sup`. An empty `sub` is a subset of every array."]
+                #[doc = "Both arrays are walked once in `O(A + B)` time, and the result is unspecified if either of them is not sorted."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<is_sorted_subset_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const KEYS: [" $tpe "; 3] = [1 as " $tpe ", 1 as " $tpe ", 3 as " $tpe "];"]
+                #[doc = "const MASTER: [" $tpe "; 4] = [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "];"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(" [<is_sorted_subset_ $tpe _array>] "(&KEYS, &MASTER));"]
+                #[doc = "const _: () = assert!(!" [<is_sorted_subset_ $tpe _array>] "(&MASTER, &KEYS));"]
+                #[doc = "```"]
+                pub const fn [<is_sorted_subset_ $tpe _array>]<const A: usize, const B: usize>(sub: &[$tpe; A], sup: &[$tpe; B]) -> bool {
+                    let mut i = 0;
+                    let mut j = 0;
+                    while i < A {
+                        if j == B || [<less_than_ $tpe>](sub[i], sup[j]) {
+                            return false;
+                        } else if [<greater_than_ $tpe>](sub[i], sup[j]) {
+                            j += 1;
+                        } else {
+                            // `j` is not advanced, so later copies of this element in `sub` match it as well.
+                            i += 1;
+                        }
+                    }
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_subset! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_sorted_subset! {f32, f64}
+
+/// Defines `const` functions that sort arrays of the given types and verify the result or the input.
+macro_rules! impl_const_checked_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s with [`" [<into_sorted_ $tpe _array>] "`] and verifies that the result"]
+                #[doc = "is sorted and a permutation of the input before returning it."]
+                #[doc = ""]
+                #[doc = "The verification does not use the sorting algorithm: it checks that every element is no greater than the next,"]
+                #[doc = "and binary searches the output for every element of the input to check that each value occurs"]
+                #[doc = "the same number of times in both. This takes O(N log N) time in addition to the sort."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the output of the sort is not a sorted permutation of the input, which would be a bug in this crate."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_checked>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_checked>] "([1 as " $tpe ", " $tpe "::MAX, 0 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [0 as " $tpe ", 1 as " $tpe ", " $tpe "::MAX]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_checked>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+
+                    let mut i = 1;
+                    while i < N {
+                        assert!(![<greater_than_ $tpe>](sorted[i - 1], sorted[i]), "the output of the sort is not sorted");
+                        i += 1;
+                    }
+
+                    // Count the occurrences of every input value at the start of its run in the output.
+                    let mut counts = [0_usize; N];
+                    i = 0;
+                    while i < N {
+                        let index = [<lower_bound_ $tpe>](&sorted, array[i]);
+                        assert!(
+                            index < N && ![<less_than_ $tpe>](array[i], sorted[index]),
+                            "the output of the sort is missing an element of the input"
+                        );
+                        counts[index] += 1;
+                        i += 1;
+                    }
+                    i = 0;
+                    while i < N {
+                        let run_length = [<upper_bound_ $tpe>](&sorted, sorted[i]) - i;
+                        assert!(counts[i] == run_length, "the output of the sort is not a permutation of the input");
+                        i += run_length;
+                    }
+
+                    sorted
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it, but panics if it contains any duplicates."]
+                #[doc = ""]
+                #[doc = "This is meant for building sets of distinct keys, e.g. the domain of a perfect hash function, at compile time."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if two elements of the array are equal. In a `const` context this is a compile error."]
+                #[doc = "Panics during `const` evaluation can only have fixed messages, so the message can not name the duplicated value."]
+                #[doc = "It can be found with [`" [<into_sorted_dup_mask_ $tpe _array>] "`] at runtime."]
+                #[doc = ""]
+                #[doc = "```compile_fail"]
+                #[doc = "use compile_time_sort::" [<into_sorted_unique_or_panic_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const KEYS: [" $tpe "; 3] = " [<into_sorted_unique_or_panic_ $tpe _array>] "([2 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_unique_or_panic_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const KEYS: [" $tpe "; 3] = " [<into_sorted_unique_or_panic_ $tpe _array>] "([2 as " $tpe ", 0 as " $tpe ", 1 as " $tpe "]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(KEYS, [0 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_unique_or_panic_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> [$tpe; N] {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut i = 1;
+                    while i < N {
+                        assert!([<less_than_ $tpe>](sorted[i - 1], sorted[i]), "the array contains duplicates");
+                        i += 1;
+                    }
+                    sorted
+                }
+            }
+        )+
+    };
+}
+
+impl_const_checked_sort! {
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+#[rustversion::since(1.83.0)]
+impl_const_checked_sort! {f32, f64}
+
+/// Defines `const` functions that sort arrays of the given types and verify that their elements are in a range
+/// or spaced a minimum distance apart.
+macro_rules! impl_const_range_checked_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and verifies that every element is in the range `min..=max` before returning it."]
+                #[doc = ""]
+                #[doc = "This catches out-of-range data in `const` tables at compile time. The range is checked on the"]
+                #[doc = "smallest and largest element of the sorted array, so the check only takes O(1) time in addition to the sort."]
+                #[doc = "Not to be confused with the `into_sorted_*_array_bounded` functions, which bound the length of the array."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if `min` is greater than `max`, or if any element of the array is outside the range."]
+                #[doc = "In a `const` context this is a compile error:"]
+                #[doc = ""]
+                #[doc = "```compile_fail"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_in_range>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_in_range>] "([3 as " $tpe ", 1 as " $tpe ", 4 as " $tpe "], 1 as " $tpe ", 3 as " $tpe ");"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_ $tpe _array_in_range>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_ $tpe _array_in_range>] "([3 as " $tpe ", 1 as " $tpe ", 2 as " $tpe "], 1 as " $tpe ", 3 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [1 as " $tpe ", 2 as " $tpe ", 3 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_ $tpe _array_in_range>]<const N: usize>(array: [$tpe; N], min: $tpe, max: $tpe) -> [$tpe; N] {
+                    assert!(![<greater_than_ $tpe>](min, max), "the minimum of the range is greater than its maximum");
+
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    if N > 0 {
+                        assert!(![<less_than_ $tpe>](sorted[0], min), "an element of the array is less than the minimum of the range");
+                        assert!(![<greater_than_ $tpe>](sorted[N - 1], max), "an element of the array is greater than the maximum of the range");
+                    }
+                    sorted
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and verifies that every pair of adjacent elements in the sorted array"]
+                #[doc = "is at least `gap` apart before returning it."]
+                #[doc = ""]
+                #[doc = "This validates spacing constraints of `const` tables at compile time. Differences that do not fit in a `" $tpe "`"]
+                #[doc = "are larger than any `gap`, so they never fail the check. A `gap` of 0 or less accepts any array."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if any two elements of the array are closer than `gap`. In a `const` context this is a compile error:"]
+                #[doc = ""]
+                #[doc = "```compile_fail"]
+                #[doc = "use compile_time_sort::" [<into_sorted_check_min_gap_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_check_min_gap_ $tpe _array>] "([20 as " $tpe ", 0 as " $tpe ", 11 as " $tpe "], 10 as " $tpe ");"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_check_min_gap_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SORTED_ARRAY: [" $tpe "; 3] = " [<into_sorted_check_min_gap_ $tpe _array>] "([20 as " $tpe ", 0 as " $tpe ", 10 as " $tpe "], 10 as " $tpe ");"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SORTED_ARRAY, [0 as " $tpe ", 10 as " $tpe ", 20 as " $tpe "]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_check_min_gap_ $tpe _array>]<const N: usize>(array: [$tpe; N], gap: $tpe) -> [$tpe; N] {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut i = 1;
+                    while i < N {
+                        if let Some(diff) = sorted[i].checked_sub(sorted[i - 1]) {
+                            assert!(diff >= gap, "two elements of the array are closer than the minimum gap");
+                        }
+                        i += 1;
+                    }
+                    sorted
+                }
+            }
+        )+
+    };
+}
+
+impl_const_range_checked_sort! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// Returns whether the two given arrays of `bool`s are equal after they have been sorted.
+///
+/// In other words, whether they contain the same number of `true`s.
+/// This is useful in `const` assertions.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sort_equal_bool_array;
+///
+/// const _: () = assert!(sort_equal_bool_array([true, false, true], [false, true, true]));
+/// const _: () = assert!(!sort_equal_bool_array([true, false, false], [false, true, true]));
+/// ```
+pub const fn sort_equal_bool_array<const N: usize>(a: [bool; N], b: [bool; N]) -> bool {
+    let mut trues = 0_isize;
+    let mut i = 0;
+    while i < N {
+        trues += a[i] as isize - b[i] as isize;
+        i += 1;
+    }
+    trues == 0
+}
+
+/// Returns the number of distinct `u8`s in the given array.
+///
+/// Marks the values that occur in a histogram like the one used by counting sort,
+/// so this runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::distinct_count_u8_array;
+///
+/// const DISTINCT: usize = distinct_count_u8_array([1, 0, 1, u8::MAX]);
+///
+/// assert_eq!(DISTINCT, 3);
+/// ```
+pub const fn distinct_count_u8_array<const N: usize>(array: [u8; N]) -> usize {
+    let mut seen = [false; u8::MAX as usize + 1];
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        if !seen[array[i] as usize] {
+            seen[array[i] as usize] = true;
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the number of distinct `i8`s in the given array.
+///
+/// Marks the values that occur in a histogram like the one used by counting sort,
+/// so this runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::distinct_count_i8_array;
+///
+/// const DISTINCT: usize = distinct_count_i8_array([1, 0, 1, i8::MIN]);
+///
+/// assert_eq!(DISTINCT, 3);
+/// ```
+pub const fn distinct_count_i8_array<const N: usize>(array: [i8; N]) -> usize {
+    let mut seen = [false; u8::MAX as usize + 1];
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        let index = (array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize;
+        if !seen[index] {
+            seen[index] = true;
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Defines `const` functions that check whether sorted arrays of the given integer types are contiguous.
+macro_rules! impl_const_is_contiguous {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Returns whether the given sorted array of `" $tpe "`s is a contiguous run of integers,"]
+                #[doc = "i.e. whether it is `start, start + 1, ..., start + N - 1` for some `start` without gaps or duplicates."]
+                #[doc = ""]
+                #[doc = "Empty arrays and arrays with a single element are trivially contiguous."]
+                #[doc = "The result is unspecified if the array is not sorted."]
+                #[doc = ""]
+                #[doc = "# Examples"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<is_contiguous_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const _: () = assert!(" [<is_contiguous_ $tpe _array>] "(&[1, 2, 3, 4]));"]
+                #[doc = "```"]
+                #[doc = ""]
+                #[doc = "Gaps and duplicates are not contiguous:"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<is_contiguous_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "assert!(!" [<is_contiguous_ $tpe _array>] "(&[1, 2, 4, 5]));"]
+                #[doc = "assert!(!" [<is_contiguous_ $tpe _array>] "(&[1, 2, 2, 3]));"]
+                #[doc = "```"]
+                pub const fn [<is_contiguous_ $tpe _array>]<const N: usize>(sorted: &[$tpe; N]) -> bool {
+                    let mut i = 1;
+                    while i < N {
+                        match sorted[i - 1].checked_add(1) {
+                            Some(next) if next == sorted[i] => {}
+                            _ => return false,
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_const_is_contiguous! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// Defines `const` functions that sort arrays of the given integer types and compute the differences between their elements.
+macro_rules! impl_const_sorted_diffs {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the differences between its adjacent elements."]
+                #[doc = ""]
+                #[doc = "Element `i` of the differences is `sorted[i + 1] - sorted[i]` for all `i < N - 1`,"]
+                #[doc = "and the last element is 0. All differences are 0 if `N <= 1`."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if a difference does not fit in a `" $tpe "`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sorted_diffs_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const DIFFS: ([" $tpe "; 4], [" $tpe "; 4]) = " [<sorted_diffs_ $tpe _array>] "([4, 0, 1, 2]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(DIFFS, ([0, 1, 2, 4], [1, 1, 2, 0]));"]
+                #[doc = "```"]
+                pub const fn [<sorted_diffs_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [$tpe; N]) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut diffs = [0; N];
+                    let mut i = 1;
+                    while i < N {
+                        diffs[i - 1] = match sorted[i].checked_sub(sorted[i - 1]) {
+                            Some(diff) => diff,
+                            None => panic!("the difference between two adjacent elements overflowed"),
+                        };
+                        i += 1;
+                    }
+                    (sorted, diffs)
+                }
+
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with its spread, i.e. `max - min`,"]
+                #[doc = "or `None` as the spread if the array is empty."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if the spread does not fit in a `" $tpe "`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_spread_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const SPREAD: ([" $tpe "; 4], Option<" $tpe ">) = " [<into_sorted_with_spread_ $tpe _array>] "([4, 1, 9, 2]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(SPREAD, ([1, 2, 4, 9], Some(8)));"]
+                #[doc = "assert_eq!(" [<into_sorted_with_spread_ $tpe _array>] "([]), ([], None));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_spread_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], Option<$tpe>) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    if N == 0 {
+                        return (sorted, None);
+                    }
+                    match sorted[N - 1].checked_sub(sorted[0]) {
+                        Some(spread) => (sorted, Some(spread)),
+                        None => panic!("the difference between the largest and smallest elements overflowed"),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_diffs! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Defines `const` functions that sort arrays of the given types and hash the result.
+macro_rules! impl_const_sorted_hash {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the 64-bit FNV-1a hash of the sorted elements."]
+                #[doc = ""]
+                #[doc = "The hash is computed over the little-endian bytes of the sorted elements, so it does not depend on the endianness"]
+                #[doc = "of the platform and is the same for every permutation of the same elements. It is not a cryptographic hash."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_hashed_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const A: ([" $tpe "; 3], u64) = " [<into_sorted_hashed_ $tpe _array>] "([3, 1, 2]);"]
+                #[doc = "const B: ([" $tpe "; 3], u64) = " [<into_sorted_hashed_ $tpe _array>] "([2, 3, 1]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(A, B);"]
+                #[doc = "assert_ne!(A.1, " [<into_sorted_hashed_ $tpe _array>] "([3, 1, 1]).1);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_hashed_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], u64) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut hash = FNV_OFFSET_BASIS;
+                    let mut i = 0;
+                    while i < N {
+                        let bytes = sorted[i].to_le_bytes();
+                        let mut j = 0;
+                        while j < bytes.len() {
+                            hash ^= bytes[j] as u64;
+                            hash = hash.wrapping_mul(FNV_PRIME);
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                    (sorted, hash)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_hash! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// Defines `const` functions that sort arrays of the given types and run-length encode the result,
+/// which is a histogram of the distinct values in the array.
+macro_rules! impl_const_sorted_rle {
+    ($($tpe:ty),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Sorts the given array of `" $tpe "`s and returns its run-length encoding together with the number of runs."]
+                #[doc = ""]
+                #[doc = "If the returned count is `k`, then the first `k` pairs are the distinct values of the array in ascending order"]
+                #[doc = "together with the number of times they occur. The remaining pairs are `(0, 0)`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<sorted_rle_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const RLE: ([(" $tpe ", usize); 5], usize) = " [<sorted_rle_ $tpe _array>] "([3, 1, 3, 3, 1]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(RLE.1, 2);"]
+                #[doc = "assert_eq!(RLE.0[..RLE.1], [(1, 2), (3, 3)]);"]
+                #[doc = "```"]
+                pub const fn [<sorted_rle_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([($tpe, usize); N], usize) {
+                    let (_, runs, count) = [<into_sorted_with_run_histogram_ $tpe _array>](array);
+                    (runs, count)
+                }
+
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with a histogram of its distinct values"]
+                #[doc = "and the number of distinct values."]
+                #[doc = ""]
+                #[doc = "If the returned count is `k`, then the first `k` pairs of the histogram are the distinct values of the array"]
+                #[doc = "in ascending order together with the number of times they occur, and the counts add up to `N`."]
+                #[doc = "The remaining pairs are `(0, 0)`. Unlike a histogram with one bucket per possible value,"]
+                #[doc = "this only takes up space in proportion to the length of the array."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_with_run_histogram_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const HISTOGRAM: ([" $tpe "; 5], [(" $tpe ", usize); 5], usize) ="]
+                #[doc = "    " [<into_sorted_with_run_histogram_ $tpe _array>] "([3, 1, 3, 3, 1]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(HISTOGRAM.0, [1, 1, 3, 3, 3]);"]
+                #[doc = "assert_eq!(HISTOGRAM.1[..HISTOGRAM.2], [(1, 2), (3, 3)]);"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_with_run_histogram_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [($tpe, usize); N], usize) {
+                    let mut runs = [(0, 0); N];
+                    if N == 0 {
+                        return (array, runs, 0);
+                    }
+
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut count = 0;
+                    runs[0] = (sorted[0], 1);
+                    let mut i = 1;
+                    while i < N {
+                        if sorted[i] == runs[count].0 {
+                            runs[count].1 += 1;
+                        } else {
+                            count += 1;
+                            runs[count] = (sorted[i], 1);
+                        }
+                        i += 1;
+                    }
+                    (sorted, runs, count + 1)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_rle! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize
+}
+
+/// Defines `const` functions that sort arrays of the given types and compute the prefix sums of the sorted elements.
+/// The sums are computed in the given wider type.
+macro_rules! impl_const_sorted_prefix_sums {
+    ($($tpe:ty => $sum:ty),+) => {
+        $(
+            paste::paste! {
+                #[rustversion::since(1.57.0)]
+                #[doc = "Sorts the given array of `" $tpe "`s and returns it together with the running sums of the sorted elements as `" $sum "`s."]
+                #[doc = ""]
+                #[doc = "Element `i` of the sums is `sorted[0] + sorted[1] + ... + sorted[i]`, so the last element is the sum of the whole array."]
+                #[doc = ""]
+                #[doc = "This function is only available on Rust versions 1.57 and above."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "Panics if a sum does not fit in a `" $sum "`."]
+                #[doc = ""]
+                #[doc = "# Example"]
+                #[doc = ""]
+                #[doc = "```"]
+                #[doc = "use compile_time_sort::" [<into_sorted_prefix_sum_ $tpe _array>] ";"]
+                #[doc = ""]
+                #[doc = "const PREFIX_SUMS: ([" $tpe "; 4], [" $sum "; 4]) = " [<into_sorted_prefix_sum_ $tpe _array>] "([4, 0, 3, 2]);"]
+                #[doc = ""]
+                #[doc = "assert_eq!(PREFIX_SUMS, ([0, 2, 3, 4], [0, 2, 5, 9]));"]
+                #[doc = "```"]
+                pub const fn [<into_sorted_prefix_sum_ $tpe _array>]<const N: usize>(array: [$tpe; N]) -> ([$tpe; N], [$sum; N]) {
+                    let sorted = [<into_sorted_ $tpe _array>](array);
+                    let mut sums = [0; N];
+                    let mut sum: $sum = 0;
+                    let mut i = 0;
+                    while i < N {
+                        sum = match sum.checked_add(sorted[i] as $sum) {
+                            Some(sum) => sum,
+                            None => panic!("the prefix sum overflowed"),
+                        };
+                        sums[i] = sum;
+                        i += 1;
+                    }
+                    (sorted, sums)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_sorted_prefix_sums! {
+    u8 => u64, i8 => i64,
+    u16 => u64, i16 => i64,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128,
+    u128 => u128, i128 => i128,
+    usize => u128, isize => i128
+}
+
+// endregion: multiset queries
+
+// region: histograms
+
+/// Returns the histogram of the given array of `u8`s, i.e. the number of times each value occurs in it.
+/// This is the same histogram that is built by the counting sort of `u8`s.
+const fn histogram_u8<const N: usize>(array: &[u8; N]) -> [usize; u8::MAX as usize + 1] {
+    let mut counts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
+    while i < N {
+        counts[array[i] as usize] += 1;
+        i += 1;
+    }
+    counts
+}
+
+/// Returns the cumulative distribution of the values in the given array of `u8`s.
+///
+/// Entry `i` of the returned array is the number of elements that are less than or equal to `i`.
+/// It is computed as the prefix sum of the histogram that is used by counting sort,
+/// and can be used to build histogram equalization tables.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::cdf_u8_array;
+///
+/// const CDF: [usize; 256] = cdf_u8_array([3, 0, 3, 1]);
+///
+/// assert_eq!(CDF[..5], [1, 2, 2, 4, 4]);
+/// assert_eq!(CDF[255], 4);
+/// ```
+pub const fn cdf_u8_array<const N: usize>(array: [u8; N]) -> [usize; u8::MAX as usize + 1] {
+    let mut cdf = histogram_u8(&array);
+    let mut i = 1;
+    while i < cdf.len() {
+        cdf[i] += cdf[i - 1];
+        i += 1;
+    }
+    cdf
+}
+
+/// Returns the index of the largest count in the given histogram, or `None` if all counts are zero.
+/// Ties are resolved in favor of the smallest index.
+const fn index_of_max_count(counts: &[usize; u8::MAX as usize + 1]) -> Option<usize> {
+    let mut max_index = 0;
+    let mut i = 1;
+    while i < counts.len() {
+        if counts[i] > counts[max_index] {
+            max_index = i;
         }
         i += 1;
     }
+    if counts[max_index] == 0 {
+        None
+    } else {
+        Some(max_index)
+    }
+}
+
+/// Returns the most frequent value in the given array of `u8`s, or `None` if the array is empty.
+///
+/// If several values are equally frequent the smallest of them is returned.
+/// This uses the histogram from counting sort directly, so it runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::mode_u8_array;
+///
+/// const MODE: Option<u8> = mode_u8_array([5, 3, 5, 3, 1]);
+///
+/// assert_eq!(MODE, Some(3));
+/// ```
+pub const fn mode_u8_array<const N: usize>(array: [u8; N]) -> Option<u8> {
+    match index_of_max_count(&histogram_u8(&array)) {
+        Some(index) => Some(index as u8),
+        None => None,
+    }
+}
 
-    i = 0;
+/// Returns the most frequent value in the given array of `i8`s, or `None` if the array is empty.
+///
+/// If several values are equally frequent the smallest of them is returned.
+/// This uses the histogram from counting sort directly, so it runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::mode_i8_array;
+///
+/// const MODE: Option<i8> = mode_i8_array([5, -3, 5, -3, 1]);
+///
+/// assert_eq!(MODE, Some(-3));
+/// ```
+pub const fn mode_i8_array<const N: usize>(array: [i8; N]) -> Option<i8> {
+    let mut counts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
     while i < N {
-        if falses > 0 {
-            array[i] = false;
-            falses -= 1;
-        } else {
-            array[i] = true;
+        counts[(array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
+        i += 1;
+    }
+    match index_of_max_count(&counts) {
+        Some(index) => Some((index as i16 + i8::MIN as i16) as i8),
+        None => None,
+    }
+}
+
+/// Returns the index into the given histogram of the value that would be at index `k` if the counted array was sorted.
+/// `k` must be less than the total count.
+const fn index_of_nth_count(counts: &[usize; u8::MAX as usize + 1], k: usize) -> usize {
+    let mut seen = 0;
+    let mut i = 0;
+    loop {
+        seen += counts[i];
+        if seen > k {
+            return i;
         }
         i += 1;
     }
+}
 
-    array
+/// Returns the `u8` that would be at index `k` if the given array was sorted,
+/// or [`SortError::IndexOutOfRange`] if `k` is not a valid index into the array.
+///
+/// This uses the histogram from counting sort directly, so it runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::{try_select_nth_u8_array, SortError};
+///
+/// const MIDDLE: Result<u8, SortError> = try_select_nth_u8_array([5, 1, 3], 1);
+/// const OUT_OF_RANGE: Result<u8, SortError> = try_select_nth_u8_array([5, 1, 3], 3);
+///
+/// assert_eq!(MIDDLE, Ok(3));
+/// assert_eq!(OUT_OF_RANGE, Err(SortError::IndexOutOfRange));
+/// ```
+pub const fn try_select_nth_u8_array<const N: usize>(
+    array: [u8; N],
+    k: usize,
+) -> Result<u8, SortError> {
+    if k >= N {
+        return Err(SortError::IndexOutOfRange);
+    }
+    Ok(index_of_nth_count(&histogram_u8(&array), k) as u8)
 }
 
-// endregion: counting sort implementations
+/// Returns the `i8` that would be at index `k` if the given array was sorted,
+/// or [`SortError::IndexOutOfRange`] if `k` is not a valid index into the array.
+///
+/// This uses the histogram from counting sort directly, so it runs in O(N + 256) time without sorting the array.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::{try_select_nth_i8_array, SortError};
+///
+/// const MIDDLE: Result<i8, SortError> = try_select_nth_i8_array([5, -1, 3], 1);
+/// const OUT_OF_RANGE: Result<i8, SortError> = try_select_nth_i8_array([5, -1, 3], 3);
+///
+/// assert_eq!(MIDDLE, Ok(3));
+/// assert_eq!(OUT_OF_RANGE, Err(SortError::IndexOutOfRange));
+/// ```
+pub const fn try_select_nth_i8_array<const N: usize>(
+    array: [i8; N],
+    k: usize,
+) -> Result<i8, SortError> {
+    if k >= N {
+        return Err(SortError::IndexOutOfRange);
+    }
+    let mut counts = [0_usize; u8::MAX as usize + 1];
+    let mut i = 0;
+    while i < N {
+        counts[(array[i] as i16 + i8::MIN.unsigned_abs() as i16) as usize] += 1;
+        i += 1;
+    }
+    Ok((index_of_nth_count(&counts, k) as i16 + i8::MIN as i16) as i8)
+}
+
+/// The number of fractional bits in the fixed-point logarithms computed by [`log2_fixed`].
+const LOG2_FRAC_BITS: u32 = 32;
+
+/// Returns the base 2 logarithm of `n` as a fixed-point number with [`LOG2_FRAC_BITS`] fractional bits, rounded down.
+///
+/// The fractional bits are found one at a time by repeatedly squaring the mantissa of `n`,
+/// so the result is off by less than one unit in the last place.
+const fn log2_fixed(n: NonZeroUsize) -> u64 {
+    let exp = ilog2(n);
+    // The mantissa `n / 2^exp` is in [1, 2). It is stored with 63 fractional bits.
+    let mut mantissa = ((n.get() as u128) << (63 - exp)) as u64;
+    let mut log = (exp as u64) << LOG2_FRAC_BITS;
+    let mut bit = 1 << (LOG2_FRAC_BITS - 1);
+    while bit > 0 {
+        let squared = (mantissa as u128 * mantissa as u128) >> 63;
+        if squared >= 1 << 64 {
+            mantissa = (squared >> 1) as u64;
+            log |= bit;
+        } else {
+            mantissa = squared as u64;
+        }
+        bit >>= 1;
+    }
+    log
+}
+
+/// Sorts the given array of `u8`s with counting sort and returns it together with the Shannon entropy of its bytes
+/// in thousandths of a bit per byte.
+///
+/// The entropy is computed from the histogram that counting sort builds, as `log2(N) - sum(c * log2(c)) / N`
+/// over the counts `c` of the values in the array. It is 0 if every byte is the same (or the array is empty),
+/// and 8000 if all 256 values occur equally often.
+/// The logarithms are computed with 32 fractional bits, so the result is within 1 of the exact entropy times 1000.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::into_sorted_with_entropy_u8_array;
+///
+/// const SORTED: ([u8; 4], u32) = into_sorted_with_entropy_u8_array([3, 1, 2, 0]);
+/// const ALL_EQUAL: ([u8; 4], u32) = into_sorted_with_entropy_u8_array([7; 4]);
+///
+/// assert_eq!(SORTED, ([0, 1, 2, 3], 2000));
+/// assert_eq!(ALL_EQUAL, ([7; 4], 0));
+/// ```
+pub const fn into_sorted_with_entropy_u8_array<const N: usize>(
+    mut array: [u8; N],
+) -> ([u8; N], u32) {
+    let n = match NonZeroUsize::new(N) {
+        Some(n) => n,
+        None => return (array, 0),
+    };
+
+    let counts = histogram_u8(&array);
+    let mut sum_of_c_log_c: u128 = 0;
+    let mut i = 0;
+    let mut value = 0;
+    while value < counts.len() {
+        let count = counts[value];
+        if let Some(c) = NonZeroUsize::new(count) {
+            sum_of_c_log_c += count as u128 * log2_fixed(c) as u128;
+        }
+        let mut j = 0;
+        while j < count {
+            array[i] = value as u8;
+            i += 1;
+            j += 1;
+        }
+        value += 1;
+    }
+
+    // Rounding errors could make this slightly negative for huge arrays with almost no entropy.
+    let scaled = (N as u128 * log2_fixed(n) as u128).saturating_sub(sum_of_c_log_c);
+    let denominator = (N as u128) << LOG2_FRAC_BITS;
+    let millibits = (scaled * 1000 + denominator / 2) / denominator;
+
+    (array, millibits as u32)
+}
+
+// endregion: histograms
+
+// region: macros
+
+/// Evaluates to the given array of the given type sorted at compile time.
+///
+/// The array is sorted in a `const` item with the `into_sorted_*_array` function for the given type,
+/// so the elements must be constant expressions.
+/// The supported types are `bool`, `char`, `u8`, `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `u128`, `i128`,
+/// `usize`, and `isize`, as well as `f32` and `f64` on Rust versions 1.83 and above.
+///
+/// This macro is only available with the `macros` feature.
+///
+/// # Example
+///
+/// ```
+/// use compile_time_sort::sorted_array;
+///
+/// let sorted = sorted_array!(i32; [3, 1, 2]);
+///
+/// assert_eq!(sorted, [1, 2, 3]);
+/// ```
+///
+/// Using a type that can not be sorted is a compile error:
+///
+/// ```compile_fail
+/// use compile_time_sort::sorted_array;
+///
+/// let sorted = sorted_array!(String; [String::new()]);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! sorted_array {
+    (bool; $($array:tt)+) => { $crate::__sorted_array!(bool, into_sorted_bool_array, $($array)+) };
+    (char; $($array:tt)+) => { $crate::__sorted_array!(char, into_sorted_char_array, $($array)+) };
+    (u8; $($array:tt)+) => { $crate::__sorted_array!(u8, into_sorted_u8_array, $($array)+) };
+    (i8; $($array:tt)+) => { $crate::__sorted_array!(i8, into_sorted_i8_array, $($array)+) };
+    (u16; $($array:tt)+) => { $crate::__sorted_array!(u16, into_sorted_u16_array, $($array)+) };
+    (i16; $($array:tt)+) => { $crate::__sorted_array!(i16, into_sorted_i16_array, $($array)+) };
+    (u32; $($array:tt)+) => { $crate::__sorted_array!(u32, into_sorted_u32_array, $($array)+) };
+    (i32; $($array:tt)+) => { $crate::__sorted_array!(i32, into_sorted_i32_array, $($array)+) };
+    (u64; $($array:tt)+) => { $crate::__sorted_array!(u64, into_sorted_u64_array, $($array)+) };
+    (i64; $($array:tt)+) => { $crate::__sorted_array!(i64, into_sorted_i64_array, $($array)+) };
+    (u128; $($array:tt)+) => { $crate::__sorted_array!(u128, into_sorted_u128_array, $($array)+) };
+    (i128; $($array:tt)+) => { $crate::__sorted_array!(i128, into_sorted_i128_array, $($array)+) };
+    (usize; $($array:tt)+) => { $crate::__sorted_array!(usize, into_sorted_usize_array, $($array)+) };
+    (isize; $($array:tt)+) => { $crate::__sorted_array!(isize, into_sorted_isize_array, $($array)+) };
+    (f32; $($array:tt)+) => { $crate::__sorted_array!(f32, into_sorted_f32_array, $($array)+) };
+    (f64; $($array:tt)+) => { $crate::__sorted_array!(f64, into_sorted_f64_array, $($array)+) };
+    ($other:ty; $($array:tt)+) => {
+        compile_error!(concat!("`sorted_array!` can not sort arrays of `", stringify!($other), "`"))
+    };
+}
+
+/// Implementation detail of [`sorted_array!`]. Sorts the given array with the given function in a `const` item.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sorted_array {
+    ($tpe:ty, $sort:ident, [$($element:expr),* $(,)?]) => {{
+        const LEN: usize = <[$tpe]>::len(&[$($element),*]);
+        const SORTED: [$tpe; LEN] = $crate::$sort([$($element),*]);
+        SORTED
+    }};
+}
+
+// endregion: macros
 
 #[cfg(test)]
 mod test {
-    use crate::ilog2;
+    use crate::{
+        ilog2, into_sorted_bool_array, into_sorted_i8_array, into_sorted_i8_array_comparison_free,
+        into_sorted_u8_array, into_sorted_u8_array_comparison_free, INSERTION_SIZE,
+    };
     use core::num::NonZeroUsize;
 
     #[test]
@@ -1008,4 +6305,125 @@ mod test {
             assert_eq!(ilog2(NonZeroUsize::new(i).unwrap()), i.ilog2());
         }
     }
+
+    /// The smallest arrays that are sorted with counting sort instead of insertion sort.
+    /// They only contain the smallest and largest values, so both ends of the histogram are used.
+    const CROSSOVER_U8_CASES: [[u8; INSERTION_SIZE + 1]; 3] = {
+        let mut alternating = [0; INSERTION_SIZE + 1];
+        let mut last_smallest = [u8::MAX; INSERTION_SIZE + 1];
+        let mut reversed = [0; INSERTION_SIZE + 1];
+        let mut i = 0;
+        while i < INSERTION_SIZE + 1 {
+            if i % 2 == 0 {
+                alternating[i] = u8::MAX;
+            }
+            if i < INSERTION_SIZE / 2 {
+                reversed[i] = u8::MAX;
+            }
+            i += 1;
+        }
+        last_smallest[INSERTION_SIZE] = 0;
+        [alternating, last_smallest, reversed]
+    };
+
+    const fn to_i8_array(array: [u8; INSERTION_SIZE + 1]) -> [i8; INSERTION_SIZE + 1] {
+        let mut out = [0; INSERTION_SIZE + 1];
+        let mut i = 0;
+        while i < out.len() {
+            out[i] = if array[i] == 0 { i8::MIN } else { i8::MAX };
+            i += 1;
+        }
+        out
+    }
+
+    #[test]
+    fn test_counting_sort_at_insertion_size_crossover() {
+        for case in CROSSOVER_U8_CASES {
+            let smallest = case.iter().filter(|&&x| x == 0).count();
+
+            let sorted = into_sorted_u8_array(case);
+            assert!(sorted.is_sorted());
+            assert_eq!(sorted.iter().filter(|&&x| x == 0).count(), smallest);
+
+            let sorted = into_sorted_i8_array(to_i8_array(case));
+            assert!(sorted.is_sorted());
+            assert_eq!(sorted.iter().filter(|&&x| x == i8::MIN).count(), smallest);
+        }
+    }
+
+    /// Returns the array `[N - 1, N - 2, ..., 0]` as `u8`s, which makes insertion sort do the most comparisons.
+    const fn reversed_u8_array<const N: usize>() -> [u8; N] {
+        let mut array = [0; N];
+        let mut i = 0;
+        while i < N {
+            array[i] = (N - 1 - i) as u8;
+            i += 1;
+        }
+        array
+    }
+
+    /// A long array in descending order, which insertion sort would need O(N^2) swaps to sort during `const` evaluation.
+    /// The comparison-free sorts only index a histogram with the elements, so this is a single O(N + 256) pass.
+    static REVERSED_U8_SORTED: [u8; 1 << 16] = into_sorted_u8_array_comparison_free({
+        let mut array = [0; 1 << 16];
+        let mut i = 0;
+        while i < array.len() {
+            array[i] = u8::MAX - (i / 256) as u8;
+            i += 1;
+        }
+        array
+    });
+
+    fn check_comparison_free_sorts<const N: usize>() {
+        let reversed = reversed_u8_array::<N>();
+        let sorted = into_sorted_u8_array_comparison_free(reversed);
+        assert!(sorted.is_sorted());
+        assert_eq!(sorted, into_sorted_u8_array(reversed));
+
+        let reversed_i8 = reversed.map(|x| (x as i16 + i8::MIN as i16) as i8);
+        let sorted = into_sorted_i8_array_comparison_free(reversed_i8);
+        assert!(sorted.is_sorted());
+        assert_eq!(sorted, into_sorted_i8_array(reversed_i8));
+
+        let falses_last = reversed.map(|x| x >= N as u8 / 2);
+        assert!(into_sorted_bool_array(falses_last).is_sorted());
+    }
+
+    #[test]
+    fn test_comparison_free_sorts() {
+        // Up to `INSERTION_SIZE` these lengths are sorted with insertion sort by `into_sorted_u8_array` and `into_sorted_i8_array`.
+        check_comparison_free_sorts::<0>();
+        check_comparison_free_sorts::<1>();
+        check_comparison_free_sorts::<2>();
+        check_comparison_free_sorts::<{ INSERTION_SIZE / 2 }>();
+        check_comparison_free_sorts::<INSERTION_SIZE>();
+        check_comparison_free_sorts::<{ INSERTION_SIZE + 1 }>();
+        check_comparison_free_sorts::<{ u8::MAX as usize }>();
+
+        assert!(REVERSED_U8_SORTED.is_sorted());
+        assert!(REVERSED_U8_SORTED
+            .chunks(256)
+            .enumerate()
+            .all(|(i, chunk)| chunk == [i as u8; 256]));
+    }
+
+    #[rustversion::since(1.83.0)]
+    #[test]
+    fn test_counting_sort_slice_at_insertion_size_crossover() {
+        use crate::{sort_i8_slice, sort_u8_slice};
+
+        for case in CROSSOVER_U8_CASES {
+            let smallest = case.iter().filter(|&&x| x == 0).count();
+
+            let mut sorted = case;
+            sort_u8_slice(&mut sorted);
+            assert!(sorted.is_sorted());
+            assert_eq!(sorted.iter().filter(|&&x| x == 0).count(), smallest);
+
+            let mut sorted = to_i8_array(case);
+            sort_i8_slice(&mut sorted);
+            assert!(sorted.is_sorted());
+            assert_eq!(sorted.iter().filter(|&&x| x == i8::MIN).count(), smallest);
+        }
+    }
 }
@@ -0,0 +1,104 @@
+// Copyright 2024-2026 Johanna Sörngård
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Sorts arrays of increasing sizes during `const` evaluation.
+//!
+//! These tests guard against changes that make `const` evaluation of the sorting functions so slow
+//! that it hits the limits of the compiler. If any of them become too slow this file fails to compile.
+//! The time it takes to evaluate them can be measured with
+//!
+//! ```text
+//! cargo clean -p compile_time_sort && time cargo test --test const_eval_sizes --no-run
+//! ```
+
+use compile_time_sort::{
+    into_sorted_i32_array, into_sorted_u64_array, try_into_sorted_i32_array, MAX_CONST_SORT_LEN,
+};
+
+/// Returns an array of pseudo-random numbers generated with a linear congruential generator.
+const fn pseudo_random_array<const N: usize>() -> [u64; N] {
+    let mut arr = [0; N];
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    let mut i = 0;
+    while i < N {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        arr[i] = state;
+        i += 1;
+    }
+    arr
+}
+
+/// Truncates every element of the given array to an `i32`.
+const fn to_i32_array<const N: usize>(array: [u64; N]) -> [i32; N] {
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = (array[i] >> 32) as i32;
+        i += 1;
+    }
+    arr
+}
+
+static SORTED_I32_256: [i32; 256] = into_sorted_i32_array(to_i32_array(pseudo_random_array()));
+static SORTED_I32_1024: [i32; 1024] = into_sorted_i32_array(to_i32_array(pseudo_random_array()));
+static SORTED_I32_4096: [i32; 4096] = into_sorted_i32_array(to_i32_array(pseudo_random_array()));
+
+static SORTED_I32_MAX_LEN: [i32; MAX_CONST_SORT_LEN] =
+    match try_into_sorted_i32_array(to_i32_array(pseudo_random_array())) {
+        Ok(sorted) => sorted,
+        Err(_) => panic!("arrays of `MAX_CONST_SORT_LEN` elements can be sorted"),
+    };
+
+static SORTED_U64_256: [u64; 256] = into_sorted_u64_array(pseudo_random_array());
+static SORTED_U64_1024: [u64; 1024] = into_sorted_u64_array(pseudo_random_array());
+static SORTED_U64_4096: [u64; 4096] = into_sorted_u64_array(pseudo_random_array());
+
+#[test]
+fn test_const_sorted_i32_arrays() {
+    assert!(SORTED_I32_256.is_sorted());
+    assert!(SORTED_I32_1024.is_sorted());
+    assert!(SORTED_I32_4096.is_sorted());
+
+    let mut expected = to_i32_array::<4096>(pseudo_random_array());
+    expected.sort();
+    assert_eq!(SORTED_I32_4096, expected);
+}
+
+#[test]
+fn test_const_try_sorted_i32_array() {
+    let mut expected = to_i32_array::<MAX_CONST_SORT_LEN>(pseudo_random_array());
+    expected.sort();
+    assert_eq!(SORTED_I32_MAX_LEN, expected);
+}
+
+#[test]
+fn test_const_sorted_u64_arrays() {
+    assert!(SORTED_U64_256.is_sorted());
+    assert!(SORTED_U64_1024.is_sorted());
+    assert!(SORTED_U64_4096.is_sorted());
+
+    let mut expected = pseudo_random_array::<4096>();
+    expected.sort();
+    assert_eq!(SORTED_U64_4096, expected);
+}
+
+#[rustversion::since(1.83.0)]
+mod slices {
+    use super::pseudo_random_array;
+    use compile_time_sort::sort_u64_slice;
+
+    static SORTED_U64_4096: [u64; 4096] = {
+        let mut arr = pseudo_random_array();
+        sort_u64_slice(&mut arr);
+        arr
+    };
+
+    #[test]
+    fn test_const_sorted_u64_slice() {
+        let mut expected = pseudo_random_array::<4096>();
+        expected.sort();
+        assert_eq!(SORTED_U64_4096, expected);
+    }
+}
@@ -6,11 +6,323 @@ use quickcheck::quickcheck;
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 
 use compile_time_sort::{
-    into_sorted_bool_array, into_sorted_char_array, into_sorted_f32_array, into_sorted_f64_array,
-    into_sorted_i128_array, into_sorted_i16_array, into_sorted_i32_array, into_sorted_i64_array,
-    into_sorted_i8_array, into_sorted_isize_array, into_sorted_str_array, into_sorted_u128_array,
-    into_sorted_u16_array, into_sorted_u32_array, into_sorted_u64_array, into_sorted_u8_array,
-    into_sorted_u8_slice_array, into_sorted_usize_array,
+    apply_permutation_i128_array, apply_permutation_i16_array, apply_permutation_i32_array,
+    apply_permutation_i64_array, apply_permutation_i8_array, apply_permutation_isize_array,
+    apply_permutation_u128_array, apply_permutation_u16_array, apply_permutation_u32_array,
+    apply_permutation_u64_array, apply_permutation_u8_array, apply_permutation_usize_array,
+    argsort_char_array_stable, argsort_i128_array_stable, argsort_i16_array_stable,
+    argsort_i32_array_stable, argsort_i64_array_stable, argsort_i8_array_stable,
+    argsort_isize_array_stable, argsort_u128_array_stable, argsort_u16_array_stable,
+    argsort_u32_array_stable, argsort_u64_array_stable, argsort_u8_array_stable,
+    argsort_usize_array_stable, argsort_with_inverse_i128_array, argsort_with_inverse_i16_array,
+    argsort_with_inverse_i32_array, argsort_with_inverse_i64_array, argsort_with_inverse_i8_array,
+    argsort_with_inverse_isize_array, argsort_with_inverse_u128_array,
+    argsort_with_inverse_u16_array, argsort_with_inverse_u32_array, argsort_with_inverse_u64_array,
+    argsort_with_inverse_u8_array, argsort_with_inverse_usize_array, cdf_u8_array,
+    equal_range_char_array, equal_range_i128_array, equal_range_i16_array, equal_range_i32_array,
+    equal_range_i64_array, equal_range_i8_array, equal_range_isize_array, equal_range_u128_array,
+    equal_range_u16_array, equal_range_u32_array, equal_range_u64_array, equal_range_u8_array,
+    equal_range_usize_array, extremes2_i128_array, extremes2_i16_array, extremes2_i32_array,
+    extremes2_i64_array, extremes2_i8_array, extremes2_isize_array, extremes2_u128_array,
+    extremes2_u16_array, extremes2_u32_array, extremes2_u64_array, extremes2_u8_array,
+    extremes2_usize_array, eytzinger_search_i128_array, eytzinger_search_i16_array,
+    eytzinger_search_i32_array, eytzinger_search_i64_array, eytzinger_search_i8_array,
+    eytzinger_search_isize_array, eytzinger_search_u128_array, eytzinger_search_u16_array,
+    eytzinger_search_u32_array, eytzinger_search_u64_array, eytzinger_search_u8_array,
+    eytzinger_search_usize_array, first_unsorted_index_char_array, first_unsorted_index_i128_array,
+    first_unsorted_index_i16_array, first_unsorted_index_i32_array, first_unsorted_index_i64_array,
+    first_unsorted_index_i8_array, first_unsorted_index_isize_array,
+    first_unsorted_index_u128_array, first_unsorted_index_u16_array,
+    first_unsorted_index_u32_array, first_unsorted_index_u64_array, first_unsorted_index_u8_array,
+    first_unsorted_index_usize_array, insert_index_after_equal_i128_array,
+    insert_index_after_equal_i16_array, insert_index_after_equal_i32_array,
+    insert_index_after_equal_i64_array, insert_index_after_equal_i8_array,
+    insert_index_after_equal_isize_array, insert_index_after_equal_u128_array,
+    insert_index_after_equal_u16_array, insert_index_after_equal_u32_array,
+    insert_index_after_equal_u64_array, insert_index_after_equal_u8_array,
+    insert_index_after_equal_usize_array, into_eytzinger_i128_array, into_eytzinger_i16_array,
+    into_eytzinger_i32_array, into_eytzinger_i64_array, into_eytzinger_i8_array,
+    into_eytzinger_isize_array, into_eytzinger_u128_array, into_eytzinger_u16_array,
+    into_eytzinger_u32_array, into_eytzinger_u64_array, into_eytzinger_u8_array,
+    into_eytzinger_usize_array, into_sorted_be_u16_bytes_array, into_sorted_bool_array,
+    into_sorted_bool_array_desc, into_sorted_bool_array_with_split, into_sorted_char_array,
+    into_sorted_char_array_ascii_casefold, into_sorted_char_array_shell,
+    into_sorted_char_array_stable, into_sorted_check_min_gap_i128_array,
+    into_sorted_check_min_gap_i16_array, into_sorted_check_min_gap_i32_array,
+    into_sorted_check_min_gap_i64_array, into_sorted_check_min_gap_i8_array,
+    into_sorted_check_min_gap_isize_array, into_sorted_check_min_gap_u128_array,
+    into_sorted_check_min_gap_u16_array, into_sorted_check_min_gap_u32_array,
+    into_sorted_check_min_gap_u64_array, into_sorted_check_min_gap_u8_array,
+    into_sorted_check_min_gap_usize_array, into_sorted_count_equal_i128_array,
+    into_sorted_count_equal_i16_array, into_sorted_count_equal_i32_array,
+    into_sorted_count_equal_i64_array, into_sorted_count_equal_i8_array,
+    into_sorted_count_equal_isize_array, into_sorted_count_equal_u128_array,
+    into_sorted_count_equal_u16_array, into_sorted_count_equal_u32_array,
+    into_sorted_count_equal_u64_array, into_sorted_count_equal_u8_array,
+    into_sorted_count_equal_usize_array, into_sorted_dedup_char_array,
+    into_sorted_dedup_i128_array, into_sorted_dedup_i16_array, into_sorted_dedup_i32_array,
+    into_sorted_dedup_i64_array, into_sorted_dedup_i8_array, into_sorted_dedup_isize_array,
+    into_sorted_dedup_u128_array, into_sorted_dedup_u16_array, into_sorted_dedup_u32_array,
+    into_sorted_dedup_u64_array, into_sorted_dedup_u8_array, into_sorted_dedup_usize_array,
+    into_sorted_distinct_flag_char_array, into_sorted_distinct_flag_i128_array,
+    into_sorted_distinct_flag_i16_array, into_sorted_distinct_flag_i32_array,
+    into_sorted_distinct_flag_i64_array, into_sorted_distinct_flag_i8_array,
+    into_sorted_distinct_flag_isize_array, into_sorted_distinct_flag_u128_array,
+    into_sorted_distinct_flag_u16_array, into_sorted_distinct_flag_u32_array,
+    into_sorted_distinct_flag_u64_array, into_sorted_distinct_flag_u8_array,
+    into_sorted_distinct_flag_usize_array, into_sorted_equals_i128_array,
+    into_sorted_equals_i16_array, into_sorted_equals_i32_array, into_sorted_equals_i64_array,
+    into_sorted_equals_i8_array, into_sorted_equals_isize_array, into_sorted_equals_u128_array,
+    into_sorted_equals_u16_array, into_sorted_equals_u32_array, into_sorted_equals_u64_array,
+    into_sorted_equals_u8_array, into_sorted_equals_usize_array, into_sorted_f16_bits_array,
+    into_sorted_f32_array, into_sorted_f64_array, into_sorted_halves_i128_array,
+    into_sorted_halves_i16_array, into_sorted_halves_i32_array, into_sorted_halves_i64_array,
+    into_sorted_halves_i8_array, into_sorted_halves_isize_array, into_sorted_halves_u128_array,
+    into_sorted_halves_u16_array, into_sorted_halves_u32_array, into_sorted_halves_u64_array,
+    into_sorted_halves_u8_array, into_sorted_halves_usize_array, into_sorted_hashed_i128_array,
+    into_sorted_hashed_i16_array, into_sorted_hashed_i32_array, into_sorted_hashed_i64_array,
+    into_sorted_hashed_i8_array, into_sorted_hashed_isize_array, into_sorted_hashed_u128_array,
+    into_sorted_hashed_u16_array, into_sorted_hashed_u32_array, into_sorted_hashed_u64_array,
+    into_sorted_hashed_u8_array, into_sorted_hashed_usize_array, into_sorted_i128_array,
+    into_sorted_i128_array_checked, into_sorted_i128_array_in_range,
+    into_sorted_i128_array_magnitude_segmented, into_sorted_i128_array_shell,
+    into_sorted_i128_array_stable_mode, into_sorted_i16_array, into_sorted_i16_array_checked,
+    into_sorted_i16_array_counting, into_sorted_i16_array_in_range,
+    into_sorted_i16_array_magnitude_segmented, into_sorted_i16_array_shell,
+    into_sorted_i16_array_stable_mode, into_sorted_i32_array, into_sorted_i32_array_checked,
+    into_sorted_i32_array_in_range, into_sorted_i32_array_magnitude_segmented,
+    into_sorted_i32_array_radix, into_sorted_i32_array_seeded, into_sorted_i32_array_shell,
+    into_sorted_i32_array_stable_mode, into_sorted_i64_array, into_sorted_i64_array_checked,
+    into_sorted_i64_array_in_range, into_sorted_i64_array_magnitude_segmented,
+    into_sorted_i64_array_shell, into_sorted_i64_array_stable_mode, into_sorted_i8_array,
+    into_sorted_i8_array_checked, into_sorted_i8_array_in_range,
+    into_sorted_i8_array_magnitude_segmented, into_sorted_i8_array_shell,
+    into_sorted_i8_array_stable_mode, into_sorted_isize_array, into_sorted_isize_array_checked,
+    into_sorted_isize_array_in_range, into_sorted_isize_array_magnitude_segmented,
+    into_sorted_isize_array_shell, into_sorted_isize_array_stable_mode,
+    into_sorted_nonzero_u8_array, into_sorted_or_unchanged_i128_array,
+    into_sorted_or_unchanged_i16_array, into_sorted_or_unchanged_i32_array,
+    into_sorted_or_unchanged_i64_array, into_sorted_or_unchanged_i8_array,
+    into_sorted_or_unchanged_isize_array, into_sorted_or_unchanged_u128_array,
+    into_sorted_or_unchanged_u16_array, into_sorted_or_unchanged_u32_array,
+    into_sorted_or_unchanged_u64_array, into_sorted_or_unchanged_u8_array,
+    into_sorted_or_unchanged_usize_array, into_sorted_prefix_sum_i128_array,
+    into_sorted_prefix_sum_i16_array, into_sorted_prefix_sum_i32_array,
+    into_sorted_prefix_sum_i64_array, into_sorted_prefix_sum_i8_array,
+    into_sorted_prefix_sum_isize_array, into_sorted_prefix_sum_u128_array,
+    into_sorted_prefix_sum_u16_array, into_sorted_prefix_sum_u32_array,
+    into_sorted_prefix_sum_u64_array, into_sorted_prefix_sum_u8_array,
+    into_sorted_prefix_sum_usize_array, into_sorted_q16_16_array, into_sorted_reverse_i32_array,
+    into_sorted_rgba_by_luma_array, into_sorted_semver_packed_array, into_sorted_str_array,
+    into_sorted_u128_array, into_sorted_u128_array_checked, into_sorted_u128_array_in_range,
+    into_sorted_u128_array_shell, into_sorted_u128_array_stable_mode, into_sorted_u16_array,
+    into_sorted_u16_array_checked, into_sorted_u16_array_counting, into_sorted_u16_array_in_range,
+    into_sorted_u16_array_shell, into_sorted_u16_array_stable_mode, into_sorted_u32_array,
+    into_sorted_u32_array_by_popcount, into_sorted_u32_array_by_swap_bytes,
+    into_sorted_u32_array_checked, into_sorted_u32_array_in_range, into_sorted_u32_array_radix,
+    into_sorted_u32_array_shell, into_sorted_u32_array_stable_mode, into_sorted_u64_array,
+    into_sorted_u64_array_checked, into_sorted_u64_array_in_range, into_sorted_u64_array_shell,
+    into_sorted_u64_array_stable_mode, into_sorted_u8_array, into_sorted_u8_array_by_rank,
+    into_sorted_u8_array_checked, into_sorted_u8_array_in_range, into_sorted_u8_array_shell,
+    into_sorted_u8_array_stable_mode, into_sorted_u8_pair_array, into_sorted_u8_slice_array,
+    into_sorted_until_sentinel_i128_array, into_sorted_until_sentinel_i16_array,
+    into_sorted_until_sentinel_i32_array, into_sorted_until_sentinel_i64_array,
+    into_sorted_until_sentinel_i8_array, into_sorted_until_sentinel_isize_array,
+    into_sorted_until_sentinel_u128_array, into_sorted_until_sentinel_u16_array,
+    into_sorted_until_sentinel_u32_array, into_sorted_until_sentinel_u64_array,
+    into_sorted_until_sentinel_u8_array, into_sorted_until_sentinel_usize_array,
+    into_sorted_usize_array, into_sorted_usize_array_checked, into_sorted_usize_array_in_range,
+    into_sorted_usize_array_shell, into_sorted_usize_array_stable_mode,
+    into_sorted_with_argmin_argmax_i128_array, into_sorted_with_argmin_argmax_i16_array,
+    into_sorted_with_argmin_argmax_i32_array, into_sorted_with_argmin_argmax_i64_array,
+    into_sorted_with_argmin_argmax_i8_array, into_sorted_with_argmin_argmax_isize_array,
+    into_sorted_with_argmin_argmax_u128_array, into_sorted_with_argmin_argmax_u16_array,
+    into_sorted_with_argmin_argmax_u32_array, into_sorted_with_argmin_argmax_u64_array,
+    into_sorted_with_argmin_argmax_u8_array, into_sorted_with_argmin_argmax_usize_array,
+    into_sorted_with_entropy_u8_array, into_sorted_with_indices_i128_array,
+    into_sorted_with_indices_i16_array, into_sorted_with_indices_i32_array,
+    into_sorted_with_indices_i64_array, into_sorted_with_indices_i8_array,
+    into_sorted_with_indices_isize_array, into_sorted_with_indices_u128_array,
+    into_sorted_with_indices_u16_array, into_sorted_with_indices_u32_array,
+    into_sorted_with_indices_u64_array, into_sorted_with_indices_u8_array,
+    into_sorted_with_indices_usize_array, into_sorted_with_max_run_char_array,
+    into_sorted_with_max_run_i128_array, into_sorted_with_max_run_i16_array,
+    into_sorted_with_max_run_i32_array, into_sorted_with_max_run_i64_array,
+    into_sorted_with_max_run_isize_array, into_sorted_with_max_run_u128_array,
+    into_sorted_with_max_run_u16_array, into_sorted_with_max_run_u32_array,
+    into_sorted_with_max_run_u64_array, into_sorted_with_max_run_usize_array,
+    into_sorted_with_sign_splits_i128_array, into_sorted_with_sign_splits_i16_array,
+    into_sorted_with_sign_splits_i32_array, into_sorted_with_sign_splits_i64_array,
+    into_sorted_with_sign_splits_i8_array, into_sorted_with_sign_splits_isize_array,
+    into_sorted_wrapping_i32_array, is_contiguous_i128_array, is_contiguous_i16_array,
+    is_contiguous_i32_array, is_contiguous_i64_array, is_contiguous_i8_array,
+    is_contiguous_isize_array, is_contiguous_u128_array, is_contiguous_u16_array,
+    is_contiguous_u32_array, is_contiguous_u64_array, is_contiguous_u8_array,
+    is_contiguous_usize_array, is_strictly_sorted_char_array, is_strictly_sorted_i128_array,
+    is_strictly_sorted_i16_array, is_strictly_sorted_i32_array, is_strictly_sorted_i64_array,
+    is_strictly_sorted_i8_array, is_strictly_sorted_isize_array, is_strictly_sorted_u128_array,
+    is_strictly_sorted_u16_array, is_strictly_sorted_u32_array, is_strictly_sorted_u64_array,
+    is_strictly_sorted_u8_array, is_strictly_sorted_usize_array, largest_k_i128_array,
+    largest_k_i16_array, largest_k_i32_array, largest_k_i64_array, largest_k_isize_array,
+    largest_k_u128_array, largest_k_u16_array, largest_k_u32_array, largest_k_u64_array,
+    largest_k_usize_array, mode_i8_array, mode_u8_array, pack_semver,
+    partition_around_value_i128_array, partition_around_value_i16_array,
+    partition_around_value_i32_array, partition_around_value_i64_array,
+    partition_around_value_i8_array, partition_around_value_isize_array,
+    partition_around_value_u128_array, partition_around_value_u16_array,
+    partition_around_value_u32_array, partition_around_value_u64_array,
+    partition_around_value_u8_array, partition_around_value_usize_array, smallest_k_i128_array,
+    smallest_k_i16_array, smallest_k_i32_array, smallest_k_i64_array, smallest_k_isize_array,
+    smallest_k_u128_array, smallest_k_u16_array, smallest_k_u32_array, smallest_k_u64_array,
+    smallest_k_usize_array, sort_indices_by_keys_i128, sort_indices_by_keys_i16,
+    sort_indices_by_keys_i32, sort_indices_by_keys_i64, sort_indices_by_keys_i8,
+    sort_indices_by_keys_isize, sort_indices_by_keys_u128, sort_indices_by_keys_u16,
+    sort_indices_by_keys_u32, sort_indices_by_keys_u64, sort_indices_by_keys_u8,
+    sort_indices_by_keys_usize, sort_prefix_char_array, sort_prefix_i128_array,
+    sort_prefix_i16_array, sort_prefix_i32_array, sort_prefix_i64_array, sort_prefix_i8_array,
+    sort_prefix_isize_array, sort_prefix_u128_array, sort_prefix_u16_array, sort_prefix_u32_array,
+    sort_prefix_u64_array, sort_prefix_u8_array, sort_prefix_usize_array,
+    sort_rows_by_col_char_array, sort_rows_by_col_i128_array, sort_rows_by_col_i16_array,
+    sort_rows_by_col_i32_array, sort_rows_by_col_i64_array, sort_rows_by_col_i8_array,
+    sort_rows_by_col_isize_array, sort_rows_by_col_u128_array, sort_rows_by_col_u16_array,
+    sort_rows_by_col_u32_array, sort_rows_by_col_u64_array, sort_rows_by_col_u8_array,
+    sort_rows_by_col_usize_array, sort_rows_i128_array, sort_rows_i16_array, sort_rows_i32_array,
+    sort_rows_i64_array, sort_rows_i8_array, sort_rows_isize_array, sort_rows_u128_array,
+    sort_rows_u16_array, sort_rows_u32_array, sort_rows_u64_array, sort_rows_u8_array,
+    sort_rows_usize_array, sorted_contains_i128_array, sorted_contains_i16_array,
+    sorted_contains_i32_array, sorted_contains_i64_array, sorted_contains_i8_array,
+    sorted_contains_isize_array, sorted_contains_u128_array, sorted_contains_u16_array,
+    sorted_contains_u32_array, sorted_contains_u64_array, sorted_contains_u8_array,
+    sorted_contains_usize_array, sorted_diffs_i128_array, sorted_diffs_i16_array,
+    sorted_diffs_i32_array, sorted_diffs_i64_array, sorted_diffs_i8_array,
+    sorted_diffs_isize_array, sorted_diffs_u128_array, sorted_diffs_u16_array,
+    sorted_diffs_u32_array, sorted_diffs_u64_array, sorted_diffs_u8_array,
+    sorted_diffs_usize_array, sorted_prefix_len_bool_array, sorted_prefix_len_char_array,
+    sorted_prefix_len_i128_array, sorted_prefix_len_i16_array, sorted_prefix_len_i32_array,
+    sorted_prefix_len_i64_array, sorted_prefix_len_i8_array, sorted_prefix_len_isize_array,
+    sorted_prefix_len_u128_array, sorted_prefix_len_u16_array, sorted_prefix_len_u32_array,
+    sorted_prefix_len_u64_array, sorted_prefix_len_u8_array, sorted_prefix_len_usize_array,
+    sorted_rle_i128_array, sorted_rle_i16_array, sorted_rle_i32_array, sorted_rle_i64_array,
+    sorted_rle_i8_array, sorted_rle_isize_array, sorted_rle_u128_array, sorted_rle_u16_array,
+    sorted_rle_u32_array, sorted_rle_u64_array, sorted_rle_u8_array, sorted_rle_usize_array,
+    sorted_with_ternary_counts_i128_array, sorted_with_ternary_counts_i16_array,
+    sorted_with_ternary_counts_i32_array, sorted_with_ternary_counts_i64_array,
+    sorted_with_ternary_counts_i8_array, sorted_with_ternary_counts_isize_array,
+    sorted_with_ternary_counts_u128_array, sorted_with_ternary_counts_u16_array,
+    sorted_with_ternary_counts_u32_array, sorted_with_ternary_counts_u64_array,
+    sorted_with_ternary_counts_u8_array, sorted_with_ternary_counts_usize_array,
+    try_select_nth_char_array, try_select_nth_i128_array, try_select_nth_i16_array,
+    try_select_nth_i32_array, try_select_nth_i64_array, try_select_nth_i8_array,
+    try_select_nth_isize_array, try_select_nth_u128_array, try_select_nth_u16_array,
+    try_select_nth_u32_array, try_select_nth_u64_array, try_select_nth_u8_array,
+    try_select_nth_usize_array, window_maxes_i128_array, window_maxes_i16_array,
+    window_maxes_i32_array, window_maxes_i64_array, window_maxes_i8_array,
+    window_maxes_isize_array, window_maxes_u128_array, window_maxes_u16_array,
+    window_maxes_u32_array, window_maxes_u64_array, window_maxes_u8_array,
+    window_maxes_usize_array, SortError,
+};
+
+use compile_time_sort::{
+    distinct_count_char_array, distinct_count_i128_array, distinct_count_i16_array,
+    distinct_count_i32_array, distinct_count_i64_array, distinct_count_i8_array,
+    distinct_count_isize_array, distinct_count_u128_array, distinct_count_u16_array,
+    distinct_count_u32_array, distinct_count_u64_array, distinct_count_u8_array,
+    distinct_count_usize_array,
+};
+
+use compile_time_sort::{
+    sort_equal_bool_array, sort_equal_char_array, sort_equal_i128_array, sort_equal_i16_array,
+    sort_equal_i32_array, sort_equal_i64_array, sort_equal_i8_array, sort_equal_isize_array,
+    sort_equal_u128_array, sort_equal_u16_array, sort_equal_u32_array, sort_equal_u64_array,
+    sort_equal_u8_array, sort_equal_usize_array,
+};
+
+use compile_time_sort::into_sorted_records_by_keyrange;
+
+use compile_time_sort::{
+    into_sorted_unique_or_panic_char_array, into_sorted_unique_or_panic_i128_array,
+    into_sorted_unique_or_panic_i16_array, into_sorted_unique_or_panic_i32_array,
+    into_sorted_unique_or_panic_i64_array, into_sorted_unique_or_panic_i8_array,
+    into_sorted_unique_or_panic_isize_array, into_sorted_unique_or_panic_u128_array,
+    into_sorted_unique_or_panic_u16_array, into_sorted_unique_or_panic_u32_array,
+    into_sorted_unique_or_panic_u64_array, into_sorted_unique_or_panic_u8_array,
+    into_sorted_unique_or_panic_usize_array,
+};
+
+use compile_time_sort::{
+    into_sorted_run_starts_char_array, into_sorted_run_starts_i128_array,
+    into_sorted_run_starts_i16_array, into_sorted_run_starts_i32_array,
+    into_sorted_run_starts_i64_array, into_sorted_run_starts_i8_array,
+    into_sorted_run_starts_isize_array, into_sorted_run_starts_u128_array,
+    into_sorted_run_starts_u16_array, into_sorted_run_starts_u32_array,
+    into_sorted_run_starts_u64_array, into_sorted_run_starts_u8_array,
+    into_sorted_run_starts_usize_array,
+};
+
+use compile_time_sort::{
+    into_sorted_with_run_histogram_i128_array, into_sorted_with_run_histogram_i16_array,
+    into_sorted_with_run_histogram_i32_array, into_sorted_with_run_histogram_i64_array,
+    into_sorted_with_run_histogram_i8_array, into_sorted_with_run_histogram_isize_array,
+    into_sorted_with_run_histogram_u128_array, into_sorted_with_run_histogram_u16_array,
+    into_sorted_with_run_histogram_u32_array, into_sorted_with_run_histogram_u64_array,
+    into_sorted_with_run_histogram_u8_array, into_sorted_with_run_histogram_usize_array,
+};
+
+use compile_time_sort::{
+    into_sorted_with_spread_i128_array, into_sorted_with_spread_i16_array,
+    into_sorted_with_spread_i32_array, into_sorted_with_spread_i64_array,
+    into_sorted_with_spread_i8_array, into_sorted_with_spread_isize_array,
+    into_sorted_with_spread_u128_array, into_sorted_with_spread_u16_array,
+    into_sorted_with_spread_u32_array, into_sorted_with_spread_u64_array,
+    into_sorted_with_spread_u8_array, into_sorted_with_spread_usize_array,
+};
+
+use compile_time_sort::{
+    into_sorted_char_array_smart, into_sorted_i128_array_smart, into_sorted_i16_array_smart,
+    into_sorted_i32_array_smart, into_sorted_i64_array_smart, into_sorted_i8_array_smart,
+    into_sorted_isize_array_smart, into_sorted_u128_array_smart, into_sorted_u16_array_smart,
+    into_sorted_u32_array_smart, into_sorted_u64_array_smart, into_sorted_u8_array_smart,
+    into_sorted_usize_array_smart,
+};
+
+use compile_time_sort::{
+    into_sorted_dup_mask_char_array, into_sorted_dup_mask_i128_array,
+    into_sorted_dup_mask_i16_array, into_sorted_dup_mask_i32_array, into_sorted_dup_mask_i64_array,
+    into_sorted_dup_mask_i8_array, into_sorted_dup_mask_isize_array,
+    into_sorted_dup_mask_u128_array, into_sorted_dup_mask_u16_array,
+    into_sorted_dup_mask_u32_array, into_sorted_dup_mask_u64_array, into_sorted_dup_mask_u8_array,
+    into_sorted_dup_mask_usize_array,
+};
+
+use compile_time_sort::{
+    into_sorted_bool_array_from_slice, into_sorted_char_array_from_slice,
+    into_sorted_i128_array_from_slice, into_sorted_i16_array_from_slice,
+    into_sorted_i32_array_from_slice, into_sorted_i64_array_from_slice,
+    into_sorted_i8_array_from_slice, into_sorted_isize_array_from_slice,
+    into_sorted_u128_array_from_slice, into_sorted_u16_array_from_slice,
+    into_sorted_u32_array_from_slice, into_sorted_u64_array_from_slice,
+    into_sorted_u8_array_from_slice, into_sorted_usize_array_from_slice, sort_bool_bits_u64,
+};
+
+use compile_time_sort::{
+    try_into_sorted_char_array, try_into_sorted_i128_array, try_into_sorted_i16_array,
+    try_into_sorted_i32_array, try_into_sorted_i64_array, try_into_sorted_isize_array,
+    try_into_sorted_u128_array, try_into_sorted_u16_array, try_into_sorted_u32_array,
+    try_into_sorted_u64_array, try_into_sorted_usize_array, MAX_CONST_SORT_LEN,
+};
+
+use compile_time_sort::{
+    is_sorted_subset_char_array, is_sorted_subset_i128_array, is_sorted_subset_i16_array,
+    is_sorted_subset_i32_array, is_sorted_subset_i64_array, is_sorted_subset_i8_array,
+    is_sorted_subset_isize_array, is_sorted_subset_u128_array, is_sorted_subset_u16_array,
+    is_sorted_subset_u32_array, is_sorted_subset_u64_array, is_sorted_subset_u8_array,
+    is_sorted_subset_usize_array,
+};
+
+use compile_time_sort::{
+    rank_char_array, rank_i128_array, rank_i16_array, rank_i32_array, rank_i64_array,
+    rank_i8_array, rank_isize_array, rank_u128_array, rank_u16_array, rank_u32_array,
+    rank_u64_array, rank_u8_array, rank_usize_array,
 };
 
 #[cfg(feature = "nested")]
@@ -24,10 +336,48 @@ use compile_time_sort::{
 
 #[rustversion::since(1.83.0)]
 use compile_time_sort::{
-    sort_bool_slice, sort_char_slice, sort_f32_slice, sort_f64_slice, sort_i128_slice,
-    sort_i16_slice, sort_i32_slice, sort_i64_slice, sort_i8_slice, sort_isize_slice,
-    sort_str_slice, sort_u128_slice, sort_u16_slice, sort_u32_slice, sort_u64_slice, sort_u8_slice,
-    sort_u8_slice_slice, sort_usize_slice,
+    argsort_f32_array_stable, argsort_with_inverse_f64_array, distinct_count_f32_array,
+    distinct_count_f64_array, equal_range_f32_array, equal_range_f64_array, extremes2_f32_array,
+    extremes2_f64_array, first_unsorted_index_f32_array, first_unsorted_index_f64_array,
+    into_sorted_dedup_f32_array, into_sorted_dedup_f64_array, into_sorted_distinct_flag_f32_array,
+    into_sorted_distinct_flag_f64_array, into_sorted_equals_f32_array,
+    into_sorted_equals_f64_array, into_sorted_f32_array_by_abs, into_sorted_f32_array_canonical,
+    into_sorted_f32_array_checked, into_sorted_f32_array_partial_cmp, into_sorted_f32_array_shell,
+    into_sorted_f64_array_by_abs, into_sorted_f64_array_canonical, into_sorted_f64_array_checked,
+    into_sorted_f64_array_shell, into_sorted_with_max_run_f32_array,
+    into_sorted_with_max_run_f64_array, is_strictly_sorted_f32_array, is_strictly_sorted_f64_array,
+    rank_f32_array, rank_f64_array, sort_equal_f32_array, sort_equal_f64_array,
+    sort_f64_slice_partial_cmp, sort_prefix_f32_array, sort_prefix_f64_array,
+    sorted_contains_f32_array, sorted_contains_f64_array, sorted_prefix_len_f32_array,
+    sorted_prefix_len_f64_array, sorted_with_ternary_counts_f32_array,
+    sorted_with_ternary_counts_f64_array, try_select_nth_f32_array, try_select_nth_f64_array,
+};
+
+#[rustversion::since(1.83.0)]
+use compile_time_sort::{
+    merge_dedup_char_arrays, merge_dedup_f32_arrays, merge_dedup_i128_arrays,
+    merge_dedup_i16_arrays, merge_dedup_i32_arrays, merge_dedup_i64_arrays, merge_dedup_i8_arrays,
+    merge_dedup_isize_arrays, merge_dedup_u128_arrays, merge_dedup_u16_arrays,
+    merge_dedup_u32_arrays, merge_dedup_u64_arrays, merge_dedup_u8_arrays,
+    merge_dedup_usize_arrays, merge_stable_char_arrays, merge_stable_f32_arrays,
+    merge_stable_i128_arrays, merge_stable_i16_arrays, merge_stable_i32_arrays,
+    merge_stable_i64_arrays, merge_stable_i8_arrays, merge_stable_isize_arrays,
+    merge_stable_u128_arrays, merge_stable_u16_arrays, merge_stable_u32_arrays,
+    merge_stable_u64_arrays, merge_stable_u8_arrays, merge_stable_usize_arrays,
+    sort_bool_array_ref, sort_bool_slice, sort_char_array_ref, sort_char_slice, sort_f32_array_ref,
+    sort_f32_slice, sort_f64_array_ref, sort_f64_slice, sort_i128_array_ref, sort_i128_slice,
+    sort_i16_array_ref, sort_i16_slice, sort_i16_slice_counting, sort_i32_array_ref,
+    sort_i32_slice, sort_i64_array_ref, sort_i64_slice, sort_i8_array_ref, sort_i8_slice,
+    sort_isize_array_ref, sort_isize_slice, sort_str_slice, sort_u128_array_ref, sort_u128_slice,
+    sort_u16_array_ref, sort_u16_slice, sort_u16_slice_counting, sort_u32_array_ref,
+    sort_u32_slice, sort_u32_slice_by_swap_bytes, sort_u64_array_ref, sort_u64_slice,
+    sort_u8_array_ref, sort_u8_slice, sort_u8_slice_slice, sort_u8_slice_with_counts,
+    sort_usize_array_ref, sort_usize_slice, write_sorted_bool_array, write_sorted_char_array,
+    write_sorted_f32_array, write_sorted_f64_array, write_sorted_i128_array,
+    write_sorted_i16_array, write_sorted_i32_array, write_sorted_i64_array, write_sorted_i8_array,
+    write_sorted_isize_array, write_sorted_u128_array, write_sorted_u16_array,
+    write_sorted_u32_array, write_sorted_u64_array, write_sorted_u8_array,
+    write_sorted_usize_array,
 };
 
 #[cfg(feature = "nested")]
@@ -214,180 +564,3734 @@ macro_rules! test_signed_integer {
 
 test_unsigned_integer! { u8, u16, u32, u64, u128, usize }
 
-test_signed_integer! { i8, i16, i32, i64, i128, isize }
+macro_rules! test_select {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_try_select_nth_ $tpe>]() {
+                    const ARRAY: [$tpe; 5] = [5, 1, 3, 3, 0];
+                    const MEDIAN: $tpe = match [<try_select_nth_ $tpe _array>](ARRAY, 2) {
+                        Ok(median) => median,
+                        Err(_) => panic!("the index is in range"),
+                    };
+                    const OUT_OF_RANGE: Result<$tpe, SortError> = [<try_select_nth_ $tpe _array>](ARRAY, 5);
+                    const EMPTY: Result<$tpe, SortError> = [<try_select_nth_ $tpe _array>]([], 0);
 
-#[rustversion::since(1.83.0)]
-quickcheck_slice_sort! {
-    u8, i8,
-    u16, i16,
-    u32, i32,
-    u64, i64,
-    u128, i128,
-    usize, isize,
-    char,
-    bool
+                    assert_eq!(MEDIAN, 3);
+                    assert_eq!(OUT_OF_RANGE, Err(SortError::IndexOutOfRange));
+                    assert_eq!(EMPTY, Err(SortError::IndexOutOfRange));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 100] = core::array::from_fn(|_| rng.gen());
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+                    for k in 0..random_array.len() {
+                        assert_eq!([<try_select_nth_ $tpe _array>](random_array, k), Ok(sorted_array[k]));
+                    }
+                }
+            }
+        )+
+    };
 }
 
-#[cfg(feature = "nested")]
+test_select! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
 #[test]
-fn test_sort_bool_slice_arrays() {
-    const ARR: [&[bool]; 3] = [&[false, false], &[true], &[true, true]];
-    const SORTED_ARR: [&[bool]; 3] = into_sorted_bool_slice_array(ARR);
+fn test_try_select_nth_floats() {
+    const MIDDLE: Result<f32, SortError> = try_select_nth_f32_array([1.0, -0.0, 0.0], 1);
+    assert_eq!(MIDDLE.map(f32::to_bits), Ok(0.0_f32.to_bits()));
+    assert_eq!(
+        try_select_nth_f32_array([1.0, -0.0, 0.0], 0).map(f32::to_bits),
+        Ok((-0.0_f32).to_bits())
+    );
+    assert_eq!(
+        try_select_nth_f32_array([1.0, -0.0, 0.0], 3),
+        Err(SortError::IndexOutOfRange)
+    );
 
-    assert!(SORTED_ARR.is_sorted());
+    let array = [f64::NAN, 2.0, f64::NEG_INFINITY, -1.0, f64::INFINITY];
+    let sorted = into_sorted_f64_array(array);
+    for (k, x) in sorted.iter().enumerate() {
+        assert_eq!(
+            try_select_nth_f64_array(array, k).map(f64::to_bits),
+            Ok(x.to_bits())
+        );
+    }
+    assert_eq!(
+        try_select_nth_f64_array([], 0),
+        Err(SortError::IndexOutOfRange)
+    );
 }
 
-#[cfg(feature = "nested")]
-#[rustversion::since(1.83.0)]
-#[test]
-fn test_sort_bool_slice_slices() {
-    const SORTED_ARR: [&[bool]; 3] = {
-        let mut arr: [&[bool]; 3] = [&[false], &[true], &[true, true]];
-        sort_bool_slice_slice(&mut arr);
-        arr
+macro_rules! test_smallest_and_largest_k {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_smallest_and_largest_k_ $tpe>]() {
+                    const ARRAY: [$tpe; 6] = [5, 1, 3, 3, 0, 4];
+                    const SMALLEST: [$tpe; 3] = [<smallest_k_ $tpe _array>](ARRAY);
+                    const LARGEST: [$tpe; 3] = [<largest_k_ $tpe _array>](ARRAY);
+                    const NONE_SMALLEST: [$tpe; 0] = [<smallest_k_ $tpe _array>](ARRAY);
+                    const NONE_LARGEST: [$tpe; 0] = [<largest_k_ $tpe _array>](ARRAY);
+                    const FROM_EMPTY: [$tpe; 0] = [<smallest_k_ $tpe _array>]([]);
+
+                    assert_eq!(SMALLEST, [0, 1, 3]);
+                    assert_eq!(LARGEST, [3, 4, 5]);
+                    assert_eq!(NONE_SMALLEST, []);
+                    assert_eq!(NONE_LARGEST, []);
+                    assert_eq!(FROM_EMPTY, []);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 100] = core::array::from_fn(|_| rng.gen());
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+
+                    let smallest: [$tpe; 10] = [<smallest_k_ $tpe _array>](random_array);
+                    let largest: [$tpe; 10] = [<largest_k_ $tpe _array>](random_array);
+                    assert_eq!(smallest, sorted_array[..10]);
+                    assert_eq!(largest, sorted_array[90..]);
+
+                    let all_smallest: [$tpe; 100] = [<smallest_k_ $tpe _array>](random_array);
+                    let all_largest: [$tpe; 100] = [<largest_k_ $tpe _array>](random_array);
+                    assert_eq!(all_smallest, sorted_array);
+                    assert_eq!(all_largest, sorted_array);
+                }
+            }
+        )+
     };
+}
 
-    assert!(SORTED_ARR.is_sorted());
+test_smallest_and_largest_k! { u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+#[should_panic]
+fn test_smallest_k_too_large() {
+    let _: [i32; 4] = smallest_k_i32_array(core::hint::black_box([1, 2, 3]));
 }
 
-macro_rules! test_unsigned_slices {
+macro_rules! test_partition_around_value {
     ($($tpe:ty),+) => {
         $(
             paste! {
                 #[test]
-                fn [<test_sort_ $tpe _slice_array>]() {
-                    const ARR: [&[$tpe]; 4] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1]];
-                    const SORTED_ARR: [&[$tpe]; 4] = [<into_sorted_ $tpe _slice_array>](ARR);
+                fn [<test_partition_around_value_ $tpe>]() {
+                    const PARTITIONED: ([$tpe; 7], usize) = [<partition_around_value_ $tpe _array>]([9, 3, 5, 1, 5, 0, 7], 5);
+                    const ALL_LESS: ([$tpe; 3], usize) = [<partition_around_value_ $tpe _array>]([1, 0, 2], 5);
+                    const NONE_LESS: ([$tpe; 3], usize) = [<partition_around_value_ $tpe _array>]([5, 6, 7], 5);
+                    const EMPTY: ([$tpe; 0], usize) = [<partition_around_value_ $tpe _array>]([], 5);
 
-                    assert!(SORTED_ARR.is_sorted());
+                    let (array, split) = PARTITIONED;
+                    assert_eq!(split, 3);
+                    let mut less = array[..split].to_vec();
+                    less.sort();
+                    assert_eq!(less, [0, 1, 3]);
+                    let mut rest = array[split..].to_vec();
+                    rest.sort();
+                    assert_eq!(rest, [5, 5, 7, 9]);
+                    assert_eq!(ALL_LESS.1, 3);
+                    assert_eq!(NONE_LESS, ([5, 6, 7], 0));
+                    assert_eq!(EMPTY.1, 0);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let threshold = random_array[0];
+                    let (array, split) = [<partition_around_value_ $tpe _array>](random_array, threshold);
+                    assert_eq!(split, random_array.iter().filter(|&&x| x < threshold).count());
+                    assert!(array[..split].iter().all(|&x| x < threshold));
+                    assert!(array[split..].iter().all(|&x| x >= threshold));
+                    assert_eq!([<into_sorted_ $tpe _array>](array), [<into_sorted_ $tpe _array>](random_array));
                 }
+            }
+        )+
+    };
+}
+
+test_partition_around_value! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
 
+macro_rules! test_rank {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
                 #[test]
-                fn [<test_sort_long_identical_ $tpe _slice_array>]() {
-                    const ARR: [&[$tpe]; 500] = [&[0]; 500];
-                    const SORTED_ARR: [&[$tpe]; 500] = [<into_sorted_ $tpe _slice_array>](ARR);
+                fn [<test_rank_ $tpe>]() {
+                    const SORTED: [$tpe; 6] = [1, 2, 2, 2, 5, 7];
+                    const RANK_OF_TWO: (usize, usize) = [<rank_ $tpe _array>](&SORTED, 2);
 
-                    assert!(SORTED_ARR.is_sorted());
+                    assert_eq!(RANK_OF_TWO, (1, 3));
+                    assert_eq!([<rank_ $tpe _array>](&SORTED, 0), (0, 0));
+                    assert_eq!([<rank_ $tpe _array>](&SORTED, 3), (4, 0));
+                    assert_eq!([<rank_ $tpe _array>](&SORTED, 7), (5, 1));
+                    assert_eq!([<rank_ $tpe _array>](&SORTED, 8), (6, 0));
+                    assert_eq!([<rank_ $tpe _array>](&[], 8), (0, 0));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 200] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+                    for x in 0..20 {
+                        let x = x as $tpe;
+                        let less = sorted_array.iter().filter(|&&y| y < x).count();
+                        let equal = sorted_array.iter().filter(|&&y| y == x).count();
+                        assert_eq!([<rank_ $tpe _array>](&sorted_array, x), (less, equal));
+                    }
                 }
 
-                #[rustversion::since(1.83.0)]
                 #[test]
-                fn [<test_sort_ $tpe _slice_slice>]() {
-                     const SORTED_ARR: [&[$tpe]; 4] = {
-                        let mut arr: [&[$tpe]; 4] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1]];
-                        [<sort_ $tpe _slice_slice>](&mut arr);
-                        arr
-                    };
+                fn [<test_sorted_contains_ $tpe>]() {
+                    const SORTED: [$tpe; 6] = [1, 2, 2, 2, 5, $tpe::MAX];
+                    const _: () = assert!([<sorted_contains_ $tpe _array>](&SORTED, 2));
+                    const _: () = assert!(![<sorted_contains_ $tpe _array>](&SORTED, 3));
+                    const _: () = assert!(![<sorted_contains_ $tpe _array>](&[], 3));
 
-                    assert!(SORTED_ARR.is_sorted());
+                    assert!([<sorted_contains_ $tpe _array>](&SORTED, 1));
+                    assert!([<sorted_contains_ $tpe _array>](&SORTED, $tpe::MAX));
+                    assert!(![<sorted_contains_ $tpe _array>](&SORTED, 0));
+                    assert!(![<sorted_contains_ $tpe _array>](&SORTED, 6));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 200] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 32);
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+                    for x in 0..40 {
+                        let x = x as $tpe;
+                        assert_eq!([<sorted_contains_ $tpe _array>](&sorted_array, x), sorted_array.contains(&x));
+                    }
                 }
 
-                #[rustversion::since(1.83.0)]
                 #[test]
-                fn [<test_sort_long_identical_ $tpe _slice_slice>]() {
-                    const SORTED_ARR: [&[$tpe]; 500] = {
-                        let mut arr: [&[$tpe]; 500] = [&[]; 500];
-                        [<sort_ $tpe _slice_slice>](&mut arr);
-                        arr
-                    };
+                fn [<test_insert_index_after_equal_ $tpe>]() {
+                    const SORTED: [$tpe; 6] = [1, 2, 2, 2, 5, 7];
+                    const AFTER_TWOS: usize = [<insert_index_after_equal_ $tpe _array>](&SORTED, 2);
 
-                    assert!(SORTED_ARR.is_sorted());
+                    assert_eq!(AFTER_TWOS, 4);
+                    assert_eq!([<insert_index_after_equal_ $tpe _array>](&SORTED, 0), 0);
+                    assert_eq!([<insert_index_after_equal_ $tpe _array>](&SORTED, 3), 4);
+                    assert_eq!([<insert_index_after_equal_ $tpe _array>](&SORTED, 7), 6);
+                    assert_eq!([<insert_index_after_equal_ $tpe _array>](&SORTED, $tpe::MAX), 6);
+                    assert_eq!([<insert_index_after_equal_ $tpe _array>](&[], 1), 0);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 200] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+                    for x in 0..20 {
+                        let x = x as $tpe;
+                        assert_eq!(
+                            [<insert_index_after_equal_ $tpe _array>](&sorted_array, x),
+                            sorted_array.partition_point(|&y| y <= x)
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_equal_range_ $tpe>]() {
+                    const SORTED: [$tpe; 6] = [1, 2, 2, 2, 5, 7];
+                    const TWOS: (usize, usize) = [<equal_range_ $tpe _array>](&SORTED, 2);
+
+                    assert_eq!(TWOS, (1, 4));
+                    assert_eq!([<equal_range_ $tpe _array>](&SORTED, 0), (0, 0));
+                    assert_eq!([<equal_range_ $tpe _array>](&SORTED, 3), (4, 4));
+                    assert_eq!([<equal_range_ $tpe _array>](&SORTED, 7), (5, 6));
+                    assert_eq!([<equal_range_ $tpe _array>](&SORTED, $tpe::MAX), (6, 6));
+                    assert_eq!([<equal_range_ $tpe _array>](&[], 1), (0, 0));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 200] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                    let sorted_array = [<into_sorted_ $tpe _array>](random_array);
+                    for x in 0..20 {
+                        let x = x as $tpe;
+                        let (lo, hi) = [<equal_range_ $tpe _array>](&sorted_array, x);
+                        assert_eq!(lo, sorted_array.partition_point(|&y| y < x));
+                        assert_eq!(hi - lo, sorted_array.iter().filter(|&&y| y == x).count());
+                    }
                 }
             }
         )+
     };
 }
 
-#[cfg(feature = "nested")]
-macro_rules! test_signed_slices {
+test_rank! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_distinct_count {
     ($($tpe:ty),+) => {
         $(
             paste! {
                 #[test]
-                fn [<test_sort_ $tpe _slice_array_with_negatives>]() {
-                    const ARR: [&[$tpe]; 8] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1], &[0, -1], &[0, 0], &[-1, 0], &[-1, -1]];
-                    const SORTED_ARR: [&[$tpe]; 8] = [<into_sorted_ $tpe _slice_array>](ARR);
+                fn [<test_distinct_count_ $tpe>]() {
+                    const EMPTY: usize = [<distinct_count_ $tpe _array>]([]);
+                    const ALL_EQUAL: usize = [<distinct_count_ $tpe _array>]([3; 100]);
+                    const ALL_DISTINCT: usize = [<distinct_count_ $tpe _array>]([4, 3, 2, 1, 0]);
 
-                    assert!(SORTED_ARR.is_sorted());
+                    assert_eq!(EMPTY, 0);
+                    assert_eq!(ALL_EQUAL, 1);
+                    assert_eq!(ALL_DISTINCT, 5);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 100);
+                    let mut expected = random_array.to_vec();
+                    expected.sort();
+                    expected.dedup();
+                    assert_eq!([<distinct_count_ $tpe _array>](random_array), expected.len());
                 }
+            }
+        )+
+    };
+}
 
-                #[rustversion::since(1.83.0)]
+test_distinct_count! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_argsort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
                 #[test]
-                fn [<test_sort_ $tpe _slice_slice_with_negatives>]() {
-                     const SORTED_ARR: [&[$tpe]; 8] = {
-                        let mut arr: [&[$tpe]; 8] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1], &[0, -1], &[0, 0], &[-1, 0], &[-1, -1]];
-                        [<sort_ $tpe _slice_slice>](&mut arr);
-                        arr
-                    };
+                fn [<test_argsort_ $tpe>]() {
+                    const PERMUTATIONS: ([usize; 5], [usize; 5]) = [<argsort_with_inverse_ $tpe _array>](&[3, 1, 2, 1, 0]);
+                    assert_eq!(PERMUTATIONS, ([4, 1, 3, 2, 0], [4, 1, 3, 2, 0]));
 
-                    assert!(SORTED_ARR.is_sorted());
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 50);
+                    let (permutation, inverse) = [<argsort_with_inverse_ $tpe _array>](&random_array);
+                    assert_eq!(permutation, [<argsort_ $tpe _array_stable>](&random_array));
+
+                    let mut expected: Vec<usize> = (0..random_array.len()).collect();
+                    expected.sort_by_key(|&i| random_array[i]);
+                    assert_eq!(permutation.to_vec(), expected);
+
+                    for i in 0..random_array.len() {
+                        assert_eq!(inverse[permutation[i]], i);
+                        assert_eq!(permutation[inverse[i]], i);
+                    }
+
+                    let sorted: Vec<$tpe> = permutation.iter().map(|&i| random_array[i]).collect();
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    let restored: Vec<$tpe> = inverse.iter().map(|&i| sorted[i]).collect();
+                    assert_eq!(restored, random_array);
+                }
+
+                #[test]
+                fn [<test_into_sorted_with_indices_ $tpe>]() {
+                    const SORTED: ([$tpe; 6], [usize; 6]) = [<into_sorted_with_indices_ $tpe _array>]([3, 1, 3, 0, 1, 3]);
+                    assert_eq!(SORTED, ([0, 1, 1, 3, 3, 3], [3, 1, 4, 0, 2, 5]));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 20);
+                    let (sorted, indices) = [<into_sorted_with_indices_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    for i in 0..random_array.len() {
+                        assert_eq!(random_array[indices[i]], sorted[i]);
+                        if i > 0 && sorted[i - 1] == sorted[i] {
+                            assert!(indices[i - 1] < indices[i]);
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_stable_mode>]() {
+                    const STABLE: ([$tpe; 6], [usize; 6]) = [<into_sorted_ $tpe _array_stable_mode>]([3, 1, 3, 0, 1, 3], false);
+                    const REVERSE_STABLE: ([$tpe; 6], [usize; 6]) = [<into_sorted_ $tpe _array_stable_mode>]([3, 1, 3, 0, 1, 3], true);
+                    const EMPTY: ([$tpe; 0], [usize; 0]) = [<into_sorted_ $tpe _array_stable_mode>]([], true);
+                    assert_eq!(STABLE, ([0, 1, 1, 3, 3, 3], [3, 1, 4, 0, 2, 5]));
+                    assert_eq!(REVERSE_STABLE, ([0, 1, 1, 3, 3, 3], [3, 4, 1, 5, 2, 0]));
+                    assert_eq!(EMPTY, ([], []));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 20);
+                    assert_eq!(
+                        [<into_sorted_ $tpe _array_stable_mode>](random_array, false),
+                        [<into_sorted_with_indices_ $tpe _array>](random_array)
+                    );
+                    let (sorted, indices) = [<into_sorted_ $tpe _array_stable_mode>](random_array, true);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    for i in 0..random_array.len() {
+                        assert_eq!(random_array[indices[i]], sorted[i]);
+                        if i > 0 && sorted[i - 1] == sorted[i] {
+                            assert!(indices[i - 1] > indices[i]);
+                        }
+                    }
                 }
             }
         )+
-
-        test_unsigned_slices! { $($tpe),+ }
     };
 }
 
-test_unsigned_slices! { u8 }
+test_argsort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
 
-#[cfg(feature = "nested")]
-test_unsigned_slices! {
-    u16, u32, u64, u128, usize
-}
+macro_rules! test_sort_indices_by_keys {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_indices_by_keys_ $tpe>]() {
+                    const KEYS: [$tpe; 6] = [5, 1, 5, 0, 1, 5];
+                    const ASCENDING: [usize; 6] = [<sort_indices_by_keys_ $tpe>]([5, 4, 3, 2, 1, 0], &KEYS, true);
+                    const DESCENDING: [usize; 6] = [<sort_indices_by_keys_ $tpe>]([0, 1, 2, 3, 4, 5], &KEYS, false);
+                    const SUBSET: [usize; 3] = [<sort_indices_by_keys_ $tpe>]([2, 4, 0], &KEYS, true);
 
-#[cfg(feature = "nested")]
-test_signed_slices! {
-    i8, i16, i32, i64, i128, isize
+                    assert_eq!(ASCENDING, [3, 4, 1, 5, 2, 0]);
+                    assert_eq!(DESCENDING, [0, 2, 5, 1, 4, 3]);
+                    assert_eq!(SUBSET, [4, 2, 0]);
+                    assert_eq!([<sort_indices_by_keys_ $tpe>]([], &KEYS, true), []);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let keys: Vec<$tpe> = (0..100).map(|_| rng.gen::<u8>() as $tpe % 10).collect();
+                    let indices: [usize; 300] = core::array::from_fn(|_| rng.gen_range(0..keys.len()));
+                    let mut expected = indices;
+                    expected.sort_by_key(|&i| keys[i]);
+                    assert_eq!([<sort_indices_by_keys_ $tpe>](indices, &keys, true), expected);
+                    expected = indices;
+                    expected.sort_by_key(|&i| core::cmp::Reverse(keys[i]));
+                    assert_eq!([<sort_indices_by_keys_ $tpe>](indices, &keys, false), expected);
+                }
+            }
+        )+
+    };
 }
 
-#[test]
-fn test_sort_str_array() {
-    const ARR: [&str; 4] = ["abc", "abd", "aaaaa", "l"];
-    const SORTED_ARR: [&str; 4] = into_sorted_str_array(ARR);
+test_sort_indices_by_keys! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
 
-    assert!(SORTED_ARR.is_sorted());
+#[test]
+#[should_panic]
+fn test_sort_indices_by_keys_out_of_bounds() {
+    sort_indices_by_keys_i32([0, 3], &[1, 2, 3], true);
 }
 
 #[rustversion::since(1.83.0)]
 #[test]
-fn test_sort_str_slice() {
-    const SORTED_ARR: [&str; 4] = {
-        let mut arr: [&str; 4] = ["abc", "abd", "aaaaa", "l"];
-        sort_str_slice(&mut arr);
-        arr
-    };
-
-    assert!(SORTED_ARR.is_sorted());
+fn test_argsort_floats() {
+    const PERMUTATIONS: ([usize; 4], [usize; 4]) =
+        argsort_with_inverse_f64_array(&[1.0, -0.0, 0.0, f64::NEG_INFINITY]);
+    assert_eq!(PERMUTATIONS, ([3, 1, 2, 0], [3, 1, 2, 0]));
+    assert_eq!(argsort_f32_array_stable(&[0.0, 0.0, -1.0]), [2, 0, 1]);
 }
 
-#[test]
-fn test_sort_bool() {
-    const ARR: [bool; 4] = [false, true, false, true];
-    const SORTED_ARR: [bool; 4] = into_sorted_bool_array(ARR);
+macro_rules! test_sorted_prefix_len {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sorted_prefix_len_ $tpe>]() {
+                    const EMPTY: usize = [<sorted_prefix_len_ $tpe _array>](&[]);
+                    const SORTED: usize = [<sorted_prefix_len_ $tpe _array>](&[0, 1, 1, 2, $tpe::MAX]);
+                    const FIRST_OUT_OF_ORDER: usize = [<sorted_prefix_len_ $tpe _array>](&[2, 1, 3]);
+                    const PARTIAL: usize = [<sorted_prefix_len_ $tpe _array>](&[1, 2, 3, 0, 4]);
 
-    assert_eq!(SORTED_ARR, [false, false, true, true]);
-}
+                    assert_eq!(EMPTY, 0);
+                    assert_eq!(SORTED, 5);
+                    assert_eq!(FIRST_OUT_OF_ORDER, 1);
+                    assert_eq!(PARTIAL, 3);
 
-#[test]
-fn test_char_sort() {
-    const SORTED_ARR: [char; 4] = into_sorted_char_array(['a', '#', '\n', 'A']);
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let mut random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    random_array[..200].sort();
+                    let expected = (1..=random_array.len())
+                        .rev()
+                        .find(|&k| random_array[..k].is_sorted())
+                        .unwrap();
+                    assert_eq!([<sorted_prefix_len_ $tpe _array>](&random_array), expected);
+                }
 
-    assert_eq!(SORTED_ARR, ['\n', '#', 'A', 'a'])
-}
+                #[test]
+                fn [<test_first_unsorted_index_ $tpe>]() {
+                    const EMPTY: Option<usize> = [<first_unsorted_index_ $tpe _array>](&[]);
+                    const SINGLE: Option<usize> = [<first_unsorted_index_ $tpe _array>](&[$tpe::MAX]);
+                    const SORTED: Option<usize> = [<first_unsorted_index_ $tpe _array>](&[0, 1, 1, 2, $tpe::MAX]);
+                    const PARTIAL: Option<usize> = [<first_unsorted_index_ $tpe _array>](&[1, 2, 3, 0, 4]);
 
-#[test]
-fn test_f32_into_sorted() {
-    const ARR: [f32; 5] = [3.0, 1.0, -0.0, 0.0, f32::MIN];
-    const SORTED_ARR: [f32; 5] = into_sorted_f32_array(ARR);
+                    assert_eq!(EMPTY, None);
+                    assert_eq!(SINGLE, None);
+                    assert_eq!(SORTED, None);
+                    assert_eq!(PARTIAL, Some(3));
+                    assert_eq!([<first_unsorted_index_ $tpe _array>](&[2, 1]), Some(1));
 
-    const CONSTANT_ARR: [f32; 3] = [1.0, 1.0, 1.0];
-    const SORTED_CONSTANT_ARR: [f32; 3] = into_sorted_f32_array(CONSTANT_ARR);
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let expected = (1..random_array.len()).find(|&i| random_array[i - 1] > random_array[i]);
+                    assert_eq!([<first_unsorted_index_ $tpe _array>](&random_array), expected);
+                }
 
-    const NAN_ARR: [f32; 3] = [f32::NAN, 1.0, -2.0];
-    const SORTED_NAN_ARR: [f32; 3] = into_sorted_f32_array(NAN_ARR);
+                #[test]
+                fn [<test_is_strictly_sorted_ $tpe>]() {
+                    const EMPTY: bool = [<is_strictly_sorted_ $tpe _array>](&[]);
+                    const SINGLE: bool = [<is_strictly_sorted_ $tpe _array>](&[$tpe::MAX]);
+                    const STRICT: bool = [<is_strictly_sorted_ $tpe _array>](&[0, 1, 2, $tpe::MAX]);
+                    const WEAK: bool = [<is_strictly_sorted_ $tpe _array>](&[1, 1, 2]);
+                    const UNSORTED: bool = [<is_strictly_sorted_ $tpe _array>](&[2, 1]);
 
-    const INF_ARR: [f32; 4] = [f32::INFINITY, 1.0, f32::NEG_INFINITY, 0.0];
+                    assert!(EMPTY);
+                    assert!(SINGLE);
+                    assert!(STRICT);
+                    assert!(!WEAK);
+                    assert!(!UNSORTED);
+                    assert!(![<is_strictly_sorted_ $tpe _array>](&[0, 1, 2, 2]));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    for _ in 0..20 {
+                        let random_array: [$tpe; 8] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64));
+                        let expected = random_array.windows(2).all(|pair| pair[0] < pair[1]);
+                        assert_eq!([<is_strictly_sorted_ $tpe _array>](&random_array), expected);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_prefix_len! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_sorted_prefix_len_other_types() {
+    assert_eq!(sorted_prefix_len_bool_array(&[false, true, false]), 2);
+    assert_eq!(sorted_prefix_len_char_array(&['a', 'b', 'c']), 3);
+    assert_eq!(first_unsorted_index_char_array(&['a', 'c', 'b']), Some(2));
+    assert!(is_strictly_sorted_char_array(&['a', 'b', 'c']));
+    assert!(!is_strictly_sorted_char_array(&['a', 'a']));
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sorted_prefix_len_floats() {
+    assert_eq!(sorted_prefix_len_f32_array(&[-0.0, 0.0, -0.0]), 2);
+    assert_eq!(
+        sorted_prefix_len_f64_array(&[f64::NEG_INFINITY, 1.0, f64::NAN, 0.0]),
+        3
+    );
+    assert_eq!(first_unsorted_index_f32_array(&[0.0, -0.0]), Some(1));
+    assert_eq!(first_unsorted_index_f64_array(&[-0.0, 0.0, f64::NAN]), None);
+    // -0.0 is less than 0.0 in the total order.
+    assert!(is_strictly_sorted_f32_array(&[-0.0, 0.0, f32::NAN]));
+    assert!(!is_strictly_sorted_f64_array(&[0.0, 0.0]));
+}
+
+macro_rules! test_sign_splits {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sign_splits_ $tpe>]() {
+                    const SPLIT: ([$tpe; 8], usize, usize) =
+                        [<into_sorted_with_sign_splits_ $tpe _array>]([3, -2, 0, $tpe::MAX, -7, 0, 1, 0]);
+                    const ALL_NEGATIVE: ([$tpe; 3], usize, usize) = [<into_sorted_with_sign_splits_ $tpe _array>]([-1, -3, -2]);
+                    const ALL_POSITIVE: ([$tpe; 3], usize, usize) = [<into_sorted_with_sign_splits_ $tpe _array>]([1, 3, 2]);
+                    const EMPTY: ([$tpe; 0], usize, usize) = [<into_sorted_with_sign_splits_ $tpe _array>]([]);
+
+                    assert_eq!(SPLIT, ([-7, -2, 0, 0, 0, 1, 3, $tpe::MAX], 2, 5));
+                    assert_eq!(ALL_NEGATIVE, ([-3, -2, -1], 3, 3));
+                    assert_eq!(ALL_POSITIVE, ([1, 2, 3], 0, 0));
+                    assert_eq!(EMPTY, ([], 0, 0));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<i8>() as $tpe % 4);
+                    let (sorted, non_negative, positive) = [<into_sorted_with_sign_splits_ $tpe _array>](random_array);
+                    assert!(sorted[..non_negative].iter().all(|&x| x < 0));
+                    assert!(sorted[non_negative..positive].iter().all(|&x| x == 0));
+                    assert!(sorted[positive..].iter().all(|&x| x > 0));
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! test_magnitude_segmented {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_magnitude_segmented>]() {
+                    const MIXED: [$tpe; 8] = [<into_sorted_ $tpe _array_magnitude_segmented>]([3, -2, 0, $tpe::MAX, -7, 0, 1, $tpe::MIN]);
+                    const ONLY_NEGATIVE: [$tpe; 3] = [<into_sorted_ $tpe _array_magnitude_segmented>]([-1, -3, -2]);
+                    const ONLY_POSITIVE: [$tpe; 3] = [<into_sorted_ $tpe _array_magnitude_segmented>]([1, 3, 2]);
+                    const ZEROS: [$tpe; 3] = [<into_sorted_ $tpe _array_magnitude_segmented>]([0, -1, 0]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_magnitude_segmented>]([]);
+
+                    assert_eq!(MIXED, [0, 0, 1, 3, $tpe::MAX, -2, -7, $tpe::MIN]);
+                    assert_eq!(ONLY_NEGATIVE, [-1, -2, -3]);
+                    assert_eq!(ONLY_POSITIVE, [1, 2, 3]);
+                    assert_eq!(ZEROS, [0, 0, -1]);
+                    assert_eq!(EMPTY, []);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<i8>() as $tpe);
+                    let mut expected = random_array;
+                    expected.sort_by_key(|&x| (x < 0, (x as i128).abs()));
+                    assert_eq!([<into_sorted_ $tpe _array_magnitude_segmented>](random_array), expected);
+                }
+            }
+        )+
+    };
+}
+
+test_sign_splits! { i8, i16, i32, i64, i128, isize }
+
+test_magnitude_segmented! { i8, i16, i32, i64, i128, isize }
+
+macro_rules! test_ternary_counts {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sorted_with_ternary_counts_ $tpe>]() {
+                    const PRESENT: ([$tpe; 7], usize, usize, usize) =
+                        [<sorted_with_ternary_counts_ $tpe _array>]([5, 2, 9, 5, 1, $tpe::MAX, 5], 5);
+                    const ABSENT: ([$tpe; 4], usize, usize, usize) = [<sorted_with_ternary_counts_ $tpe _array>]([8, 2, 6, 4], 5);
+                    const BELOW_ALL: ([$tpe; 3], usize, usize, usize) = [<sorted_with_ternary_counts_ $tpe _array>]([3, 2, 1], 0);
+                    const ABOVE_ALL: ([$tpe; 3], usize, usize, usize) = [<sorted_with_ternary_counts_ $tpe _array>]([3, 2, 1], $tpe::MAX);
+                    const EMPTY: ([$tpe; 0], usize, usize, usize) = [<sorted_with_ternary_counts_ $tpe _array>]([], 1);
+
+                    assert_eq!(PRESENT, ([1, 2, 5, 5, 5, 9, $tpe::MAX], 2, 3, 2));
+                    assert_eq!(ABSENT, ([2, 4, 6, 8], 2, 0, 2));
+                    assert_eq!(BELOW_ALL, ([1, 2, 3], 0, 0, 3));
+                    assert_eq!(ABOVE_ALL, ([1, 2, 3], 3, 0, 0));
+                    assert_eq!(EMPTY, ([], 0, 0, 0));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 8);
+                    let threshold = 3;
+                    let (sorted, lt, eq, gt) = [<sorted_with_ternary_counts_ $tpe _array>](random_array, threshold);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!(lt, random_array.iter().filter(|&&x| x < threshold).count());
+                    assert_eq!(eq, random_array.iter().filter(|&&x| x == threshold).count());
+                    assert_eq!(gt, random_array.iter().filter(|&&x| x > threshold).count());
+
+                    const SEVERAL: ([$tpe; 6], usize) = [<into_sorted_count_equal_ $tpe _array>]([5, 2, 5, $tpe::MAX, 5, 1], 5);
+                    const MISSING: ([$tpe; 3], usize) = [<into_sorted_count_equal_ $tpe _array>]([3, 1, 2], 0);
+                    const ALL: ([$tpe; 3], usize) = [<into_sorted_count_equal_ $tpe _array>]([7, 7, 7], 7);
+                    const NO_ELEMENTS: ([$tpe; 0], usize) = [<into_sorted_count_equal_ $tpe _array>]([], 7);
+                    assert_eq!(SEVERAL, ([1, 2, 5, 5, 5, $tpe::MAX], 3));
+                    assert_eq!(MISSING, ([1, 2, 3], 0));
+                    assert_eq!(ALL, ([7, 7, 7], 3));
+                    assert_eq!(NO_ELEMENTS, ([], 0));
+                    assert_eq!([<into_sorted_count_equal_ $tpe _array>](random_array, threshold), (sorted, eq));
+                }
+            }
+        )+
+    };
+}
+
+test_ternary_counts! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sorted_with_ternary_counts_floats() {
+    let (sorted, lt, eq, gt) =
+        sorted_with_ternary_counts_f32_array([1.0, -0.0, f32::NAN, 0.0, -1.0], 0.0);
+    assert_eq!(
+        sorted.map(f32::to_bits),
+        [-1.0, -0.0, 0.0, 1.0, f32::NAN].map(f32::to_bits)
+    );
+    assert_eq!((lt, eq, gt), (2, 1, 2));
+
+    let (_, lt, eq, gt) = sorted_with_ternary_counts_f64_array([2.0, 1.0], f64::INFINITY);
+    assert_eq!((lt, eq, gt), (2, 0, 0));
+}
+
+macro_rules! test_sentinel_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_until_sentinel_ $tpe>]() {
+                    const WITH_SENTINELS: ([$tpe; 6], usize) =
+                        [<into_sorted_until_sentinel_ $tpe _array>]([$tpe::MAX, 4, 0, $tpe::MAX, 7, $tpe::MAX], $tpe::MAX);
+                    const WITHOUT_SENTINELS: ([$tpe; 3], usize) = [<into_sorted_until_sentinel_ $tpe _array>]([4, 0, 7], $tpe::MAX);
+                    const ONLY_SENTINELS: ([$tpe; 2], usize) = [<into_sorted_until_sentinel_ $tpe _array>]([$tpe::MAX; 2], $tpe::MAX);
+                    const EMPTY: ([$tpe; 0], usize) = [<into_sorted_until_sentinel_ $tpe _array>]([], $tpe::MAX);
+                    // The sentinel is neither in the array nor its maximum.
+                    const ABSENT_SENTINEL: ([$tpe; 4], usize) = [<into_sorted_until_sentinel_ $tpe _array>]([9, 1, 8, 2], 5);
+
+                    assert_eq!(WITH_SENTINELS, ([0, 4, 7, $tpe::MAX, $tpe::MAX, $tpe::MAX], 3));
+                    assert_eq!(WITHOUT_SENTINELS, ([0, 4, 7], 3));
+                    assert_eq!(ONLY_SENTINELS, ([$tpe::MAX; 2], 0));
+                    assert_eq!(EMPTY, ([], 0));
+                    assert_eq!(ABSENT_SENTINEL, ([1, 2, 8, 9], 2));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| if rng.gen_bool(0.3) { $tpe::MAX } else { rng.gen::<u8>() as $tpe });
+                    let (sorted, end) = [<into_sorted_until_sentinel_ $tpe _array>](random_array, $tpe::MAX);
+                    assert_eq!(end, random_array.iter().filter(|&&x| x != $tpe::MAX).count());
+                    assert!(sorted[end..].iter().all(|&x| x == $tpe::MAX));
+                }
+            }
+        )+
+    };
+}
+
+test_sentinel_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_shellsort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_shell>]() {
+                    const SORTED: [$tpe; 6] = [<into_sorted_ $tpe _array_shell>]([3, 1, 2, 1, $tpe::MAX, 0]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_shell>]([]);
+                    const ONE: [$tpe; 1] = [<into_sorted_ $tpe _array_shell>]([1]);
+                    assert_eq!(SORTED, [0, 1, 1, 2, 3, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!(ONE, [1]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 500] = core::array::from_fn(|_| rng.gen());
+                    let mut expected = random_array;
+                    expected.sort();
+                    assert_eq!([<into_sorted_ $tpe _array_shell>](random_array), expected);
+                    assert_eq!([<into_sorted_ $tpe _array_shell>](expected), expected);
+                    let mut reversed = expected;
+                    reversed.reverse();
+                    assert_eq!([<into_sorted_ $tpe _array_shell>](reversed), expected);
+
+                    // Long enough to use gaps larger than those in Ciura's sequence.
+                    let random_array: [$tpe; 5000] = core::array::from_fn(|_| rng.gen());
+                    let mut expected = random_array;
+                    expected.sort();
+                    assert_eq!([<into_sorted_ $tpe _array_shell>](random_array), expected);
+                }
+            }
+        )+
+    };
+}
+
+test_shellsort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_shellsort_other_types() {
+    const SORTED_CHARS: [char; 4] = into_sorted_char_array_shell(['d', 'a', 'c', 'b']);
+    assert_eq!(SORTED_CHARS, ['a', 'b', 'c', 'd']);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_shellsort_floats() {
+    let sorted = into_sorted_f32_array_shell([1.0, f32::NAN, -0.0, 0.0, f32::NEG_INFINITY]);
+    assert_eq!(
+        sorted.map(f32::to_bits),
+        [f32::NEG_INFINITY, -0.0, 0.0, 1.0, f32::NAN].map(f32::to_bits)
+    );
+    assert_eq!(into_sorted_f64_array_shell([2.0, -1.0]), [-1.0, 2.0]);
+}
+
+macro_rules! test_sorted_or_unchanged {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_or_unchanged_ $tpe>]() {
+                    const UNSORTED: Result<[$tpe; 5], [$tpe; 5]> = [<into_sorted_or_unchanged_ $tpe _array>]([3, 1, $tpe::MAX, 1, 0]);
+                    const SORTED: Result<[$tpe; 5], [$tpe; 5]> = [<into_sorted_or_unchanged_ $tpe _array>]([0, 1, 1, 3, $tpe::MAX]);
+                    const EMPTY: Result<[$tpe; 0], [$tpe; 0]> = [<into_sorted_or_unchanged_ $tpe _array>]([]);
+                    const CHANGED: bool = match UNSORTED {
+                        Ok(_) => true,
+                        Err(_) => false,
+                    };
+
+                    assert!(CHANGED);
+                    assert_eq!(UNSORTED, Ok([0, 1, 1, 3, $tpe::MAX]));
+                    assert_eq!(SORTED, Err([0, 1, 1, 3, $tpe::MAX]));
+                    assert_eq!(EMPTY, Err([]));
+                    assert_eq!([<into_sorted_or_unchanged_ $tpe _array>]([1, 0]), Ok([0, 1]));
+                    assert_eq!([<into_sorted_or_unchanged_ $tpe _array>]([7; 3]), Err([7; 3]));
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_or_unchanged! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_smart_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_smart>]() {
+                    const ASCENDING: [$tpe; 6] = [<into_sorted_ $tpe _array_smart>]([0, 1, 1, 3, 3, $tpe::MAX]);
+                    const DESCENDING: [$tpe; 6] = [<into_sorted_ $tpe _array_smart>]([$tpe::MAX, 3, 3, 1, 1, 0]);
+                    const UNSORTED: [$tpe; 6] = [<into_sorted_ $tpe _array_smart>]([3, 1, $tpe::MAX, 1, 0, 1]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_smart>]([]);
+
+                    assert_eq!(ASCENDING, [0, 1, 1, 3, 3, $tpe::MAX]);
+                    assert_eq!(DESCENDING, [0, 1, 1, 3, 3, $tpe::MAX]);
+                    assert_eq!(UNSORTED, [0, 1, 1, 1, 3, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!([<into_sorted_ $tpe _array_smart>]([7; 5]), [7; 5]);
+                    assert_eq!([<into_sorted_ $tpe _array_smart>]([2, 1, 0]), [0, 1, 2]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 301] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64);
+                    let sorted = [<into_sorted_ $tpe _array>](random_array);
+                    let mut descending = sorted;
+                    descending.reverse();
+                    assert_eq!([<into_sorted_ $tpe _array_smart>](random_array), sorted);
+                    assert_eq!([<into_sorted_ $tpe _array_smart>](sorted), sorted);
+                    assert_eq!([<into_sorted_ $tpe _array_smart>](descending), sorted);
+                }
+            }
+        )+
+    };
+}
+
+test_smart_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_into_sorted_char_array_smart() {
+    assert_eq!(
+        into_sorted_char_array_smart(['c', 'b', 'b', 'a']),
+        ['a', 'b', 'b', 'c']
+    );
+    assert_eq!(
+        into_sorted_char_array_smart(['b', 'c', 'a']),
+        ['a', 'b', 'c']
+    );
+}
+
+macro_rules! test_sort_rows {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_rows_ $tpe>]() {
+                    const SORTED_ROWS: [[$tpe; 4]; 3] = [<sort_rows_ $tpe _array>]([
+                        [9, 3, 5, 1],
+                        [0, 0, $tpe::MAX, 0],
+                        [4, 3, 2, 1],
+                    ]);
+                    const NO_ROWS: [[$tpe; 4]; 0] = [<sort_rows_ $tpe _array>]([]);
+                    const NO_COLUMNS: [[$tpe; 0]; 3] = [<sort_rows_ $tpe _array>]([[]; 3]);
+
+                    assert_eq!(SORTED_ROWS, [[1, 3, 5, 9], [0, 0, 0, $tpe::MAX], [1, 2, 3, 4]]);
+                    assert!(NO_ROWS.is_empty());
+                    assert_eq!(NO_COLUMNS, [[]; 3]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [[$tpe; 50]; 10] = core::array::from_fn(|_| core::array::from_fn(|_| rng.gen()));
+                    let sorted_rows = [<sort_rows_ $tpe _array>](random_array);
+                    for (sorted_row, row) in sorted_rows.iter().zip(random_array) {
+                        let mut expected = row;
+                        expected.sort();
+                        assert_eq!(*sorted_row, expected);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_sort_rows! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_sort_rows_by_col {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_rows_by_col_ $tpe>]() {
+                    // The first column records the original position of the row.
+                    const TABLE: [[$tpe; 3]; 5] = [<sort_rows_by_col_ $tpe _array>]([
+                        [0, 5, 9],
+                        [1, 2, 8],
+                        [2, 5, 7],
+                        [3, $tpe::MAX, 6],
+                        [4, 2, 5],
+                    ], 1);
+                    const NO_ROWS: [[$tpe; 2]; 0] = [<sort_rows_by_col_ $tpe _array>]([], 1);
+
+                    assert_eq!(TABLE, [[1, 2, 8], [4, 2, 5], [0, 5, 9], [2, 5, 7], [3, $tpe::MAX, 6]]);
+                    assert!(NO_ROWS.is_empty());
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [[$tpe; 2]; 300] = core::array::from_fn(|i| [i as $tpe, rng.gen::<u8>() as $tpe % 8]);
+                    let mut expected = random_array;
+                    expected.sort_by_key(|row| row[1]);
+                    assert_eq!([<sort_rows_by_col_ $tpe _array>](random_array, 1), expected);
+                }
+            }
+        )+
+    };
+}
+
+test_sort_rows_by_col! { u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_sort_rows_by_col_other_types() {
+    const CHARS: [[char; 2]; 3] =
+        sort_rows_by_col_char_array([['a', 'c'], ['b', 'a'], ['c', 'c']], 1);
+    assert_eq!(CHARS, [['b', 'a'], ['a', 'c'], ['c', 'c']]);
+    assert_eq!(
+        sort_rows_by_col_u8_array([[3, 1], [2, 0], [1, 1]], 0),
+        [[1, 1], [2, 0], [3, 1]]
+    );
+    assert_eq!(
+        sort_rows_by_col_i8_array([[3, -1], [2, 0], [1, -1]], 1),
+        [[3, -1], [1, -1], [2, 0]]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_sort_rows_by_col_out_of_bounds() {
+    sort_rows_by_col_i32_array([[1, 2], [3, 4]], 2);
+}
+
+macro_rules! test_sorted_prefix_sums {
+    ($($tpe:ty => $sum:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_prefix_sum_ $tpe>]() {
+                    const PREFIX_SUMS: ([$tpe; 5], [$sum; 5]) = [<into_sorted_prefix_sum_ $tpe _array>]([3, 1, 0, 1, 2]);
+                    const EMPTY: ([$tpe; 0], [$sum; 0]) = [<into_sorted_prefix_sum_ $tpe _array>]([]);
+                    assert_eq!(PREFIX_SUMS, ([0, 1, 1, 2, 3], [0, 1, 2, 4, 7]));
+                    assert_eq!(EMPTY, ([], []));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe);
+                    let (sorted, sums) = [<into_sorted_prefix_sum_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!(sums[299], random_array.iter().map(|&x| x as $sum).sum::<$sum>());
+                    for i in 1..300 {
+                        assert_eq!(sums[i] - sums[i - 1], sorted[i] as $sum);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_prefix_sums! {
+    u8 => u64, i8 => i64,
+    u16 => u64, i16 => i64,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128,
+    u128 => u128, i128 => i128,
+    usize => u128, isize => i128
+}
+
+#[test]
+fn test_into_sorted_prefix_sum_negative() {
+    const PREFIX_SUMS: ([i32; 4], [i64; 4]) =
+        into_sorted_prefix_sum_i32_array([i32::MAX, i32::MIN, i32::MIN, -1]);
+    assert_eq!(PREFIX_SUMS.0, [i32::MIN, i32::MIN, -1, i32::MAX]);
+    assert_eq!(
+        PREFIX_SUMS.1,
+        [
+            i32::MIN as i64,
+            2 * i32::MIN as i64,
+            2 * i32::MIN as i64 - 1,
+            i32::MIN as i64 - 2
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_into_sorted_prefix_sum_overflow() {
+    into_sorted_prefix_sum_u128_array([u128::MAX, 1]);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_into_sorted_floats_canonical() {
+    const SORTED_F32: [f32; 6] = into_sorted_f32_array_canonical([0.0, 2.0, -0.0, -1.0, -0.0, 0.0]);
+    const ZEROS_F64: [f64; 4] = into_sorted_f64_array_canonical([-0.0, 0.0, -0.0, -0.0]);
+
+    assert_eq!(
+        SORTED_F32.map(f32::to_bits),
+        [-1.0, 0.0, 0.0, 0.0, 0.0, 2.0].map(f32::to_bits)
+    );
+    assert_eq!(ZEROS_F64.map(f64::to_bits), [0.0_f64; 4].map(f64::to_bits));
+
+    let with_nan = into_sorted_f32_array_canonical([f32::NAN, -0.0, -f32::NAN, 1.0]);
+    assert!(with_nan[0].is_nan() && with_nan[0].is_sign_negative());
+    assert_eq!(with_nan[1].to_bits(), 0.0_f32.to_bits());
+    assert_eq!(with_nan[2], 1.0);
+    assert!(with_nan[3].is_nan() && with_nan[3].is_sign_positive());
+
+    let (deduped, count) =
+        into_sorted_dedup_f64_array(into_sorted_f64_array_canonical([0.0, -0.0, 1.0]));
+    assert_eq!(count, 2);
+    assert_eq!(deduped[..count], [0.0, 1.0]);
+}
+
+#[test]
+fn test_radix_sort() {
+    const SORTED_I32: [i32; 6] = into_sorted_i32_array_radix([3, -1, i32::MAX, 0, i32::MIN, -1]);
+    const SORTED_U32: [u32; 5] = into_sorted_u32_array_radix([0x0100_0000, 255, u32::MAX, 0, 256]);
+    const EMPTY: [i32; 0] = into_sorted_i32_array_radix([]);
+    assert_eq!(SORTED_I32, [i32::MIN, -1, -1, 0, 3, i32::MAX]);
+    assert_eq!(SORTED_U32, [0, 255, 256, 0x0100_0000, u32::MAX]);
+    assert_eq!(EMPTY, []);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_i32: [i32; 1000] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_i32;
+    expected.sort();
+    assert_eq!(into_sorted_i32_array_radix(random_i32), expected);
+    assert_eq!(into_sorted_i32_array_radix(expected), expected);
+    let mut reversed = expected;
+    reversed.reverse();
+    assert_eq!(into_sorted_i32_array_radix(reversed), expected);
+
+    let duplicates: [i32; 1000] = core::array::from_fn(|_| rng.gen_range(-3..3));
+    let mut expected = duplicates;
+    expected.sort();
+    assert_eq!(into_sorted_i32_array_radix(duplicates), expected);
+
+    let random_u32: [u32; 1000] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_u32;
+    expected.sort();
+    assert_eq!(into_sorted_u32_array_radix(random_u32), expected);
+}
+
+#[test]
+fn test_seeded_sort() {
+    const SORTED: [i32; 6] = into_sorted_i32_array_seeded([3, -1, i32::MAX, 0, i32::MIN, -1], 7);
+    const EMPTY: [i32; 0] = into_sorted_i32_array_seeded([], 7);
+    assert_eq!(SORTED, [i32::MIN, -1, -1, 0, 3, i32::MAX]);
+    assert_eq!(EMPTY, []);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_i32: [i32; 1000] = core::array::from_fn(|_| rng.gen());
+    let duplicates: [i32; 1000] = core::array::from_fn(|_| rng.gen_range(-3..3));
+    let mut expected_random = random_i32;
+    expected_random.sort();
+    let mut reversed = expected_random;
+    reversed.reverse();
+    let mut expected_duplicates = duplicates;
+    expected_duplicates.sort();
+
+    for seed in [0, 1, 42, u64::MAX] {
+        assert_eq!(
+            into_sorted_i32_array_seeded(random_i32, seed),
+            expected_random
+        );
+        assert_eq!(
+            into_sorted_i32_array_seeded(expected_random, seed),
+            expected_random
+        );
+        assert_eq!(
+            into_sorted_i32_array_seeded(reversed, seed),
+            expected_random
+        );
+        assert_eq!(
+            into_sorted_i32_array_seeded(duplicates, seed),
+            expected_duplicates
+        );
+    }
+}
+
+macro_rules! test_range_checked_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_in_range>]() {
+                    const SORTED: [$tpe; 5] = [<into_sorted_ $tpe _array_in_range>]([3, 1, $tpe::MAX, 1, 0], 0, $tpe::MAX);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_in_range>]([], 1, 2);
+                    const SINGLE_VALUE_RANGE: [$tpe; 2] = [<into_sorted_ $tpe _array_in_range>]([7, 7], 7, 7);
+                    assert_eq!(SORTED, [0, 1, 1, 3, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!(SINGLE_VALUE_RANGE, [7, 7]);
+                }
+
+                #[test]
+                #[should_panic]
+                fn [<test_into_sorted_ $tpe _array_in_range _above_max>]() {
+                    [<into_sorted_ $tpe _array_in_range>]([3, 1, 5], 1, 4);
+                }
+
+                #[test]
+                #[should_panic]
+                fn [<test_into_sorted_ $tpe _array_in_range _below_min>]() {
+                    [<into_sorted_ $tpe _array_in_range>]([3, 0, 2], 1, 4);
+                }
+
+                #[test]
+                #[should_panic]
+                fn [<test_into_sorted_ $tpe _array_in_range _empty_range>]() {
+                    [<into_sorted_ $tpe _array_in_range>]([], 2, 1);
+                }
+
+                #[test]
+                fn [<test_into_sorted_check_min_gap_ $tpe>]() {
+                    const SORTED: [$tpe; 4] = [<into_sorted_check_min_gap_ $tpe _array>]([$tpe::MAX, 20, 0, 10], 10);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_check_min_gap_ $tpe _array>]([], 10);
+                    const ZERO_GAP: [$tpe; 3] = [<into_sorted_check_min_gap_ $tpe _array>]([1, 0, 1], 0);
+                    const EXTREMES: [$tpe; 2] = [<into_sorted_check_min_gap_ $tpe _array>]([$tpe::MAX, $tpe::MIN], $tpe::MAX);
+                    assert_eq!(SORTED, [0, 10, 20, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!(ZERO_GAP, [0, 1, 1]);
+                    assert_eq!(EXTREMES, [$tpe::MIN, $tpe::MAX]);
+                }
+
+                #[test]
+                #[should_panic]
+                fn [<test_into_sorted_check_min_gap_ $tpe _too_close>]() {
+                    [<into_sorted_check_min_gap_ $tpe _array>]([20, 0, 11], 10);
+                }
+            }
+        )+
+    };
+}
+
+test_range_checked_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_extremes2 {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_extremes2_ $tpe>]() {
+                    const TWO: Option<($tpe, $tpe, $tpe, $tpe)> = [<extremes2_ $tpe _array>](&[$tpe::MAX, 1]);
+                    const DUPLICATES: Option<($tpe, $tpe, $tpe, $tpe)> = [<extremes2_ $tpe _array>](&[5, 0, 9, 0, 3, 9]);
+                    assert_eq!(TWO, Some((1, $tpe::MAX, 1, $tpe::MAX)));
+                    assert_eq!(DUPLICATES, Some((0, 0, 9, 9)));
+                    assert_eq!([<extremes2_ $tpe _array>](&[7; 4]), Some((7, 7, 7, 7)));
+                    assert_eq!([<extremes2_ $tpe _array>](&[1]), None);
+                    assert_eq!([<extremes2_ $tpe _array>](&[]), None);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    for _ in 0..20 {
+                        let random_array: [$tpe; 50] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                        let mut sorted = random_array;
+                        sorted.sort();
+                        assert_eq!([<extremes2_ $tpe _array>](&random_array), Some((sorted[0], sorted[1], sorted[48], sorted[49])));
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_extremes2! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_extremes2_floats() {
+    let (min, second_min, second_max, max) =
+        extremes2_f32_array(&[0.0, f32::NAN, -0.0, 1.0, f32::NEG_INFINITY]).unwrap();
+    assert_eq!(min, f32::NEG_INFINITY);
+    assert_eq!(second_min.to_bits(), (-0.0_f32).to_bits());
+    assert_eq!(second_max, 1.0);
+    assert!(max.is_nan());
+    assert_eq!(extremes2_f64_array(&[2.0, 1.0]), Some((1.0, 2.0, 1.0, 2.0)));
+}
+
+macro_rules! test_apply_permutation {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_apply_permutation_ $tpe>]() {
+                    const DATA: [$tpe; 5] = [3, $tpe::MAX, 0, 3, 1];
+                    const SORTED: ([$tpe; 5], [usize; 5]) = [<into_sorted_with_indices_ $tpe _array>](DATA);
+                    const INVERSE: [usize; 5] = [<argsort_with_inverse_ $tpe _array>](&DATA).1;
+                    const UNSORTED: [$tpe; 5] = [<apply_permutation_ $tpe _array>](SORTED.0, INVERSE);
+
+                    assert_eq!([<apply_permutation_ $tpe _array>](DATA, SORTED.1), SORTED.0);
+                    assert_eq!(UNSORTED, DATA);
+                    assert_eq!([<apply_permutation_ $tpe _array>]([], []), [0; 0]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let (permutation, inverse) = [<argsort_with_inverse_ $tpe _array>](&random_array);
+                    let sorted = [<apply_permutation_ $tpe _array>](random_array, permutation);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!([<apply_permutation_ $tpe _array>](sorted, inverse), random_array);
+                }
+            }
+        )+
+    };
+}
+
+test_apply_permutation! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+#[should_panic]
+fn test_apply_permutation_out_of_bounds() {
+    apply_permutation_u32_array([1, 2, 3], [0, 1, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_apply_permutation_repeated_index() {
+    apply_permutation_u32_array([1, 2, 3], [0, 1, 1]);
+}
+
+macro_rules! test_sorted_rle {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sorted_rle_ $tpe>]() {
+                    const EMPTY: ([($tpe, usize); 0], usize) = [<sorted_rle_ $tpe _array>]([]);
+                    const ALL_DISTINCT: ([($tpe, usize); 4], usize) = [<sorted_rle_ $tpe _array>]([3, 0, 2, 1]);
+                    const ALL_EQUAL: ([($tpe, usize); 50], usize) = [<sorted_rle_ $tpe _array>]([7; 50]);
+                    const MIXED: ([($tpe, usize); 8], usize) = [<sorted_rle_ $tpe _array>]([5, 2, 5, 2, $tpe::MAX, 2, 5, 5]);
+
+                    assert_eq!(EMPTY.1, 0);
+                    assert_eq!(ALL_DISTINCT, ([(0, 1), (1, 1), (2, 1), (3, 1)], 4));
+                    assert_eq!(ALL_EQUAL.1, 1);
+                    assert_eq!(ALL_EQUAL.0[0], (7, 50));
+                    assert_eq!(MIXED.0[..MIXED.1], [(2, 3), (5, 4), ($tpe::MAX, 1)]);
+                    assert!(MIXED.0[MIXED.1..].iter().all(|&run| run == (0, 0)));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 32);
+                    let (runs, count) = [<sorted_rle_ $tpe _array>](random_array);
+                    let decoded: Vec<$tpe> = runs[..count]
+                        .iter()
+                        .flat_map(|&(value, length)| core::iter::repeat(value).take(length))
+                        .collect();
+                    assert_eq!(decoded, [<into_sorted_ $tpe _array>](random_array));
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_rle! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_run_histogram {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_with_run_histogram_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], [($tpe, usize); 0], usize) = [<into_sorted_with_run_histogram_ $tpe _array>]([]);
+                    const ALL_DISTINCT: ([$tpe; 4], [($tpe, usize); 4], usize) =
+                        [<into_sorted_with_run_histogram_ $tpe _array>]([3, 0, $tpe::MAX, 1]);
+                    const ALL_EQUAL: ([$tpe; 50], [($tpe, usize); 50], usize) = [<into_sorted_with_run_histogram_ $tpe _array>]([7; 50]);
+
+                    assert_eq!(EMPTY, ([], [], 0));
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 3, $tpe::MAX], [(0, 1), (1, 1), (3, 1), ($tpe::MAX, 1)], 4));
+                    assert_eq!(ALL_EQUAL.0, [7; 50]);
+                    assert_eq!(ALL_EQUAL.1[..ALL_EQUAL.2], [(7, 50)]);
+                    assert!(ALL_EQUAL.1[1..].iter().all(|&run| run == (0, 0)));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 32);
+                    let (sorted, histogram, count) = [<into_sorted_with_run_histogram_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!((histogram, count), [<sorted_rle_ $tpe _array>](random_array));
+                    assert_eq!(count, [<distinct_count_ $tpe _array>](random_array));
+                    assert_eq!(histogram[..count].iter().map(|&(_, n)| n).sum::<usize>(), 300);
+                    for &(value, n) in &histogram[..count] {
+                        assert_eq!(random_array.iter().filter(|&&x| x == value).count(), n);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_run_histogram! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_sorted_hash {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_hashed_ $tpe>]() {
+                    const A: ([$tpe; 5], u64) = [<into_sorted_hashed_ $tpe _array>]([3, 1, $tpe::MAX, 1, 0]);
+                    const B: ([$tpe; 5], u64) = [<into_sorted_hashed_ $tpe _array>]([1, 0, 1, $tpe::MAX, 3]);
+                    const C: ([$tpe; 5], u64) = [<into_sorted_hashed_ $tpe _array>]([3, 1, $tpe::MAX, 0, 0]);
+                    const EMPTY: ([$tpe; 0], u64) = [<into_sorted_hashed_ $tpe _array>]([]);
+
+                    assert_eq!(A, B);
+                    assert_ne!(A.1, C.1);
+                    // The hash of no bytes is the offset basis.
+                    assert_eq!(EMPTY.1, 0xcbf2_9ce4_8422_2325);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let mut shuffled = random_array;
+                    shuffled.reverse();
+                    let (sorted, hash) = [<into_sorted_hashed_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!([<into_sorted_hashed_ $tpe _array>](shuffled).1, hash);
+
+                    let expected = sorted
+                        .iter()
+                        .flat_map(|x| x.to_le_bytes())
+                        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3));
+                    assert_eq!(hash, expected);
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_hash! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_window_maxes {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_window_maxes_ $tpe>]() {
+                    const MAXES: ([$tpe; 7], usize) = [<window_maxes_ $tpe _array>]::<7, 3>(&[1, 3, 2, 0, 1, $tpe::MAX, 4]);
+                    const WHOLE_ARRAY: ([$tpe; 4], usize) = [<window_maxes_ $tpe _array>]::<4, 4>(&[1, 5, 2, 0]);
+                    const SINGLE_ELEMENT_WINDOWS: ([$tpe; 3], usize) = [<window_maxes_ $tpe _array>]::<3, 1>(&[2, 0, 1]);
+
+                    assert_eq!(MAXES.0[..MAXES.1], [3, 3, 2, $tpe::MAX, $tpe::MAX]);
+                    assert_eq!(WHOLE_ARRAY.0[..WHOLE_ARRAY.1], [5]);
+                    assert_eq!(SINGLE_ELEMENT_WINDOWS, ([2, 0, 1], 3));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 100] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                    let (maxes, count) = [<window_maxes_ $tpe _array>]::<100, 7>(&random_array);
+                    let expected: Vec<$tpe> = random_array.windows(7).map(|window| *window.iter().max().unwrap()).collect();
+                    assert_eq!(&maxes[..count], expected);
+                }
+            }
+        )+
+    };
+}
+
+test_window_maxes! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+#[should_panic]
+fn test_window_maxes_empty_window() {
+    window_maxes_i32_array::<3, 0>(&[1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_window_maxes_too_long_window() {
+    window_maxes_i32_array::<3, 4>(&[1, 2, 3]);
+}
+
+macro_rules! test_sorted_halves {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sorted_halves_ $tpe>]() {
+                    const EVEN: ([$tpe; 4], usize) = [<into_sorted_halves_ $tpe _array>]([3, 0, 2, 1]);
+                    const ODD: ([$tpe; 3], usize) = [<into_sorted_halves_ $tpe _array>]([$tpe::MAX, 0, $tpe::MIN]);
+                    const EMPTY: ([$tpe; 0], usize) = [<into_sorted_halves_ $tpe _array>]([]);
+                    assert_eq!(EVEN, ([0, 1, 2, 3], 2));
+                    assert_eq!(ODD, ([$tpe::MIN, 0, $tpe::MAX], 1));
+                    assert_eq!(EMPTY, ([], 0));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 101] = core::array::from_fn(|_| rng.gen());
+                    let (sorted, mid) = [<into_sorted_halves_ $tpe _array>](random_array);
+                    assert!(sorted[..mid].is_sorted());
+                    assert!(sorted[mid..].is_sorted());
+                    assert!(sorted[..mid].iter().max() <= sorted[mid..].iter().min());
+                    assert!((sorted.len() - mid).abs_diff(mid) <= 1);
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_halves! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_eytzinger {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_eytzinger_ $tpe>]() {
+                    const TREE: [$tpe; 7] = [<into_eytzinger_ $tpe _array>]([6, 0, 5, 1, 4, 2, 3]);
+                    const EMPTY: [$tpe; 0] = [<into_eytzinger_ $tpe _array>]([]);
+                    assert_eq!(TREE, [3, 1, 5, 0, 2, 4, 6]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!([<eytzinger_search_ $tpe _array>](&EMPTY, 0), None);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    for _ in 0..10 {
+                        let random_array: [$tpe; 100] = core::array::from_fn(|_| (rng.gen::<u8>() % 64 * 2) as $tpe);
+                        let tree = [<into_eytzinger_ $tpe _array>](random_array);
+
+                        let mut sorted = tree;
+                        sorted.sort();
+                        let mut expected = random_array;
+                        expected.sort();
+                        assert_eq!(sorted, expected);
+
+                        for (k, &node) in tree.iter().enumerate() {
+                            if let Some(&left) = tree.get(2 * k + 1) {
+                                assert!(left <= node);
+                            }
+                            if let Some(&right) = tree.get(2 * k + 2) {
+                                assert!(node <= right);
+                            }
+                        }
+
+                        for x in 0..128_u8 {
+                            let x = x as $tpe;
+                            match [<eytzinger_search_ $tpe _array>](&tree, x) {
+                                Some(index) => assert_eq!(tree[index], x),
+                                None => assert!(!random_array.contains(&x)),
+                            }
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_eytzinger! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_prefix_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_prefix_ $tpe>]() {
+                    const PARTIALLY_FILLED: [$tpe; 5] = [<sort_prefix_ $tpe _array>]([$tpe::MAX, 1, 0, 9, 4], 3);
+                    const FULL: [$tpe; 3] = [<sort_prefix_ $tpe _array>]([2, 1, 0], 3);
+                    const UNUSED: [$tpe; 3] = [<sort_prefix_ $tpe _array>]([2, 1, 0], 0);
+                    assert_eq!(PARTIALLY_FILLED, [0, 1, $tpe::MAX, 9, 4]);
+                    assert_eq!(FULL, [0, 1, 2]);
+                    assert_eq!(UNUSED, [2, 1, 0]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    for len in [1, 2, 9, 100, 299, 300] {
+                        let mut expected = random_array;
+                        expected[..len].sort();
+                        assert_eq!([<sort_prefix_ $tpe _array>](random_array, len), expected);
+                    }
+
+                    // A long prefix of duplicates exhausts the recursion depth and makes introsort fall back to heapsort,
+                    // which must also leave the tail untouched.
+                    let mut duplicates = [5; 42];
+                    duplicates[40] = 9;
+                    duplicates[41] = 1;
+                    let mut expected = [5; 42];
+                    expected[40] = 9;
+                    expected[41] = 1;
+                    assert_eq!([<sort_prefix_ $tpe _array>](duplicates, 40), expected);
+
+                    let mut marked: [$tpe; 100] = [7; 100];
+                    for (i, x) in marked.iter_mut().enumerate().skip(60) {
+                        *x = (100 - i) as $tpe;
+                    }
+                    assert_eq!([<sort_prefix_ $tpe _array>](marked, 60), marked);
+                    assert_eq!([<sort_prefix_ $tpe _array>]([7; 100], 60), [7; 100]);
+                }
+            }
+        )+
+    };
+}
+
+test_prefix_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+#[should_panic]
+fn test_sort_prefix_too_long() {
+    sort_prefix_i32_array([3, 2, 1], 4);
+}
+
+#[test]
+fn test_sort_prefix_char() {
+    const PARTIALLY_FILLED: [char; 5] = sort_prefix_char_array(['c', 'a', 'b', 'z', 'y'], 3);
+    assert_eq!(PARTIALLY_FILLED, ['a', 'b', 'c', 'z', 'y']);
+
+    // Exhausts the recursion depth so that the heapsort fallback sorts the prefix.
+    let mut duplicates = ['e'; 42];
+    duplicates[40] = 'z';
+    duplicates[41] = 'a';
+    assert_eq!(sort_prefix_char_array(duplicates, 40), duplicates);
+
+    let mut marked = ['h'; 100];
+    for (i, c) in marked.iter_mut().enumerate().skip(60) {
+        *c = char::from(b'a' + (i % 26) as u8);
+    }
+    assert_eq!(sort_prefix_char_array(marked, 60), marked);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_prefix_floats() {
+    const PARTIALLY_FILLED: [f32; 5] = sort_prefix_f32_array([1.0, 0.0, -0.0, f32::NAN, -1.0], 3);
+    assert_eq!(
+        PARTIALLY_FILLED.map(f32::to_bits),
+        [-0.0, 0.0, 1.0, f32::NAN, -1.0].map(f32::to_bits)
+    );
+
+    // Exhausts the recursion depth so that the heapsort fallback sorts the prefix.
+    let mut duplicates = [5.0; 42];
+    duplicates[40] = 9.0;
+    duplicates[41] = f64::NEG_INFINITY;
+    assert_eq!(
+        sort_prefix_f64_array(duplicates, 40).map(f64::to_bits),
+        duplicates.map(f64::to_bits)
+    );
+
+    let mut marked = [7.0; 100];
+    for (i, x) in marked.iter_mut().enumerate().skip(60) {
+        *x = -(i as f64);
+    }
+    assert_eq!(
+        sort_prefix_f64_array(marked, 60).map(f64::to_bits),
+        marked.map(f64::to_bits)
+    );
+}
+
+macro_rules! test_argmin_argmax {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_with_argmin_argmax_ $tpe>]() {
+                    // The extremes are duplicated, and the first occurrence of each is returned.
+                    const DUPLICATES: Option<([$tpe; 6], usize, usize)> =
+                        [<into_sorted_with_argmin_argmax_ $tpe _array>](&[3, $tpe::MAX, 0, 5, $tpe::MAX, 0]);
+                    const ALL_EQUAL: Option<([$tpe; 3], usize, usize)> = [<into_sorted_with_argmin_argmax_ $tpe _array>](&[4, 4, 4]);
+                    const SINGLE: Option<([$tpe; 1], usize, usize)> = [<into_sorted_with_argmin_argmax_ $tpe _array>](&[1]);
+                    const EMPTY: Option<([$tpe; 0], usize, usize)> = [<into_sorted_with_argmin_argmax_ $tpe _array>](&[]);
+                    assert_eq!(DUPLICATES, Some(([0, 0, 3, 5, $tpe::MAX, $tpe::MAX], 2, 1)));
+                    assert_eq!(ALL_EQUAL, Some(([4, 4, 4], 0, 0)));
+                    assert_eq!(SINGLE, Some(([1], 0, 0)));
+                    assert_eq!(EMPTY, None);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 16);
+                    let (sorted, argmin, argmax) = [<into_sorted_with_argmin_argmax_ $tpe _array>](&random_array).unwrap();
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert_eq!(argmin, random_array.iter().position(|&x| x == sorted[0]).unwrap());
+                    assert_eq!(argmax, random_array.iter().position(|&x| x == sorted[299]).unwrap());
+                }
+            }
+        )+
+    };
+}
+
+test_argmin_argmax! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_sorted_subset {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_is_sorted_subset_ $tpe>]() {
+                    const MASTER: [$tpe; 5] = [0, 2, 3, 5, $tpe::MAX];
+                    const _: () = assert!([<is_sorted_subset_ $tpe _array>](&[2, 5, $tpe::MAX], &MASTER));
+                    const _: () = assert!([<is_sorted_subset_ $tpe _array>](&MASTER, &MASTER));
+                    const _: () = assert!([<is_sorted_subset_ $tpe _array>](&[], &MASTER));
+                    const _: () = assert!([<is_sorted_subset_ $tpe _array>](&[], &[]));
+                    // Multiplicity is ignored.
+                    const _: () = assert!([<is_sorted_subset_ $tpe _array>](&[3, 3, 3], &MASTER));
+                    const _: () = assert!(![<is_sorted_subset_ $tpe _array>](&[2, 4], &MASTER));
+                    const _: () = assert!(![<is_sorted_subset_ $tpe _array>](&[1], &MASTER));
+                    const _: () = assert!(![<is_sorted_subset_ $tpe _array>](&[0], &[]));
+                    const _: () = assert!(![<is_sorted_subset_ $tpe _array>](&MASTER, &[0, 2, 3, 5]));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let sup: [$tpe; 300] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64));
+                    let sub: [$tpe; 40] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64));
+                    assert_eq!(
+                        [<is_sorted_subset_ $tpe _array>](&sub, &sup),
+                        sub.iter().all(|x| sup.contains(x)),
+                    );
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_subset! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_is_sorted_subset_char() {
+    const MASTER: [char; 4] = ['a', 'c', 'x', char::MAX];
+    const _: () = assert!(is_sorted_subset_char_array(&['a', 'a', char::MAX], &MASTER));
+    const _: () = assert!(is_sorted_subset_char_array(&MASTER, &MASTER));
+    const _: () = assert!(!is_sorted_subset_char_array(&['b'], &MASTER));
+    const _: () = assert!(!is_sorted_subset_char_array(&MASTER, &['a', 'c']));
+}
+
+macro_rules! test_from_slice_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_from_slice>]() {
+                    static DATA: &[$tpe] = &[5, 1, $tpe::MAX, 3, 0, 2];
+                    const PREFIX: [$tpe; 4] = [<into_sorted_ $tpe _array_from_slice>](&[5, 1, $tpe::MAX, 3, 0, 2]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_from_slice>](&[]);
+                    assert_eq!(PREFIX, [1, 3, 5, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+                    assert_eq!([<into_sorted_ $tpe _array_from_slice>]::<4>(DATA), PREFIX);
+                    assert_eq!([<into_sorted_ $tpe _array_from_slice>]::<6>(DATA), [0, 1, 2, 3, 5, $tpe::MAX]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_vec: Vec<$tpe> = (0..300).map(|_| rng.gen()).collect();
+                    let mut expected = random_vec.clone();
+                    expected.sort();
+                    assert_eq!([<into_sorted_ $tpe _array_from_slice>]::<300>(&random_vec), expected[..]);
+                }
+            }
+        )+
+    };
+}
+
+test_from_slice_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_into_sorted_array_from_slice_char_and_bool() {
+    static CHARS: &[char] = &['c', 'a', 'b'];
+    static BOOLS: &[bool] = &[true, false, true];
+    assert_eq!(
+        into_sorted_char_array_from_slice::<3>(CHARS),
+        ['a', 'b', 'c']
+    );
+    assert_eq!(into_sorted_bool_array_from_slice::<2>(BOOLS), [false, true]);
+}
+
+#[test]
+#[should_panic]
+fn test_into_sorted_array_from_slice_too_short() {
+    static DATA: &[i32] = &[3, 2, 1];
+    into_sorted_i32_array_from_slice::<4>(DATA);
+}
+
+macro_rules! test_try_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_try_into_sorted_ $tpe>]() {
+                    const SORTED: [$tpe; 5] = match [<try_into_sorted_ $tpe _array>]([5, 1, 3, $tpe::MAX, 0]) {
+                        Ok(sorted) => sorted,
+                        Err(_) => panic!("the array is short enough to sort"),
+                    };
+                    const EMPTY: Result<[$tpe; 0], SortError> = [<try_into_sorted_ $tpe _array>]([]);
+                    const TOO_LARGE: Result<[$tpe; MAX_CONST_SORT_LEN + 1], SortError> =
+                        [<try_into_sorted_ $tpe _array>]([1; MAX_CONST_SORT_LEN + 1]);
+
+                    assert_eq!(SORTED, [0, 1, 3, 5, $tpe::MAX]);
+                    assert_eq!(EMPTY, Ok([]));
+                    assert_eq!(TOO_LARGE, Err(SortError::TooLargeForConst));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    assert_eq!([<try_into_sorted_ $tpe _array>](random_array), Ok([<into_sorted_ $tpe _array>](random_array)));
+                }
+            }
+        )+
+    };
+}
+
+test_try_sort! { u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_try_into_sorted_char() {
+    const SORTED: Result<[char; 3], SortError> = try_into_sorted_char_array(['c', 'a', 'b']);
+
+    assert_eq!(SORTED, Ok(['a', 'b', 'c']));
+    assert_eq!(
+        try_into_sorted_char_array(['a'; MAX_CONST_SORT_LEN + 1]),
+        Err(SortError::TooLargeForConst)
+    );
+}
+
+macro_rules! test_unique_or_panic {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_unique_or_panic_ $tpe>]() {
+                    const KEYS: [$tpe; 5] = [<into_sorted_unique_or_panic_ $tpe _array>]([5, 1, $tpe::MAX, 3, 0]);
+                    const SINGLE: [$tpe; 1] = [<into_sorted_unique_or_panic_ $tpe _array>]([$tpe::MAX]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_unique_or_panic_ $tpe _array>]([]);
+                    assert_eq!(KEYS, [0, 1, 3, 5, $tpe::MAX]);
+                    assert_eq!(SINGLE, [$tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+
+                    let distinct: [$tpe; 100] = core::array::from_fn(|i| (99 - i) as $tpe);
+                    assert_eq!([<into_sorted_unique_or_panic_ $tpe _array>](distinct), [<into_sorted_ $tpe _array>](distinct));
+                }
+            }
+        )+
+    };
+}
+
+test_unique_or_panic! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_into_sorted_unique_or_panic_char() {
+    const KEYS: [char; 3] = into_sorted_unique_or_panic_char_array(['c', 'a', 'b']);
+    assert_eq!(KEYS, ['a', 'b', 'c']);
+}
+
+#[test]
+#[should_panic(expected = "the array contains duplicates")]
+fn test_into_sorted_unique_or_panic_duplicate() {
+    into_sorted_unique_or_panic_i32_array([3, 1, 2, 1]);
+}
+
+macro_rules! test_checked_sort {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_ $tpe _array_checked>]() {
+                    const SORTED: [$tpe; 6] = [<into_sorted_ $tpe _array_checked>]([3, 1, 2, 1, $tpe::MAX, 0]);
+                    const EMPTY: [$tpe; 0] = [<into_sorted_ $tpe _array_checked>]([]);
+                    assert_eq!(SORTED, [0, 1, 1, 2, 3, $tpe::MAX]);
+                    assert_eq!(EMPTY, []);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    for _ in 0..10 {
+                        let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                        assert_eq!([<into_sorted_ $tpe _array_checked>](random_array), [<into_sorted_ $tpe _array>](random_array));
+                        let few_values: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 8);
+                        assert_eq!([<into_sorted_ $tpe _array_checked>](few_values), [<into_sorted_ $tpe _array>](few_values));
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_checked_sort! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_into_sorted_floats_checked() {
+    const SORTED: [f64; 5] =
+        into_sorted_f64_array_checked([f64::NAN, 1.0, -0.0, 0.0, f64::NEG_INFINITY]);
+    assert_eq!(SORTED[..4], [f64::NEG_INFINITY, -0.0, 0.0, 1.0]);
+    assert!(SORTED[4].is_nan());
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [f32; 300] = core::array::from_fn(|_| rng.gen());
+    assert_eq!(
+        into_sorted_f32_array_checked(random_array),
+        into_sorted_f32_array(random_array)
+    );
+}
+
+macro_rules! test_sorted_diffs {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sorted_diffs_ $tpe>]() {
+                    const EVEN: ([$tpe; 5], [$tpe; 5]) = [<sorted_diffs_ $tpe _array>]([20, 5, 15, 0, 10]);
+                    const UNEVEN: ([$tpe; 5], [$tpe; 5]) = [<sorted_diffs_ $tpe _array>]([7, 1, 1, 2, 30]);
+                    const SINGLE: ([$tpe; 1], [$tpe; 1]) = [<sorted_diffs_ $tpe _array>]([$tpe::MAX]);
+                    const EMPTY: ([$tpe; 0], [$tpe; 0]) = [<sorted_diffs_ $tpe _array>]([]);
+
+                    assert_eq!(EVEN, ([0, 5, 10, 15, 20], [5, 5, 5, 5, 0]));
+                    assert_eq!(UNEVEN, ([1, 1, 2, 7, 30], [0, 1, 5, 23, 0]));
+                    assert_eq!(SINGLE, ([$tpe::MAX], [0]));
+                    assert_eq!(EMPTY, ([], []));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 100);
+                    let (sorted, diffs) = [<sorted_diffs_ $tpe _array>](random_array);
+                    for i in 0..sorted.len() - 1 {
+                        assert_eq!(sorted[i] + diffs[i], sorted[i + 1]);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_diffs! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+#[should_panic]
+fn test_sorted_diffs_overflow() {
+    sorted_diffs_i32_array([i32::MAX, i32::MIN]);
+}
+
+macro_rules! test_sorted_spread {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_with_spread_ $tpe>]() {
+                    const SPREAD: ([$tpe; 5], Option<$tpe>) = [<into_sorted_with_spread_ $tpe _array>]([20, 5, 15, 5, 10]);
+                    const SINGLE: ([$tpe; 1], Option<$tpe>) = [<into_sorted_with_spread_ $tpe _array>]([$tpe::MAX]);
+                    const EMPTY: ([$tpe; 0], Option<$tpe>) = [<into_sorted_with_spread_ $tpe _array>]([]);
+
+                    assert_eq!(SPREAD, ([5, 5, 10, 15, 20], Some(15)));
+                    assert_eq!(SINGLE, ([$tpe::MAX], Some(0)));
+                    assert_eq!(EMPTY, ([], None));
+                    assert_eq!([<into_sorted_with_spread_ $tpe _array>]([$tpe::MAX, 0]).1, Some($tpe::MAX));
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_spread! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_into_sorted_with_spread_cents() {
+    // Amounts in cents, from a large debit to a large credit.
+    const LEDGER: ([i64; 4], Option<i64>) =
+        into_sorted_with_spread_i64_array([150_000_000_000_000, -200_000_000_000_000, -1, 0]);
+    assert_eq!(
+        LEDGER,
+        (
+            [-200_000_000_000_000, -1, 0, 150_000_000_000_000],
+            Some(350_000_000_000_000)
+        )
+    );
+    assert_eq!(
+        into_sorted_with_spread_i64_array([i64::MIN, -1]).1,
+        Some(i64::MAX)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_into_sorted_with_spread_overflow() {
+    into_sorted_with_spread_i64_array([i64::MAX, -1]);
+}
+
+macro_rules! test_is_contiguous {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_is_contiguous_ $tpe>]() {
+                    const _: () = assert!([<is_contiguous_ $tpe _array>](&[]));
+                    const _: () = assert!([<is_contiguous_ $tpe _array>](&[$tpe::MAX]));
+                    const _: () = assert!([<is_contiguous_ $tpe _array>](&[<into_sorted_ $tpe _array>]([3, 1, 4, 2, 0])));
+                    const _: () = assert!(![<is_contiguous_ $tpe _array>](&[<into_sorted_ $tpe _array>]([3, 1, 5, 2, 0])));
+                    const _: () = assert!(![<is_contiguous_ $tpe _array>](&[<into_sorted_ $tpe _array>]([3, 1, 3, 2, 0])));
+                    const _: () = assert!([<is_contiguous_ $tpe _array>](&[$tpe::MAX - 2, $tpe::MAX - 1, $tpe::MAX]));
+                    const _: () = assert!(![<is_contiguous_ $tpe _array>](&[$tpe::MAX - 1, $tpe::MAX, $tpe::MAX]));
+                    const _: () = assert!(![<is_contiguous_ $tpe _array>](&[$tpe::MIN, $tpe::MAX]));
+                }
+            }
+        )+
+    };
+}
+
+test_is_contiguous! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_sort_equal {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_equal_ $tpe>]() {
+                    const GENERATED: [$tpe; 5] = [3, 1, 2, 1, 0];
+                    const _: () = assert!([<sort_equal_ $tpe _array>](GENERATED, [0, 1, 1, 2, 3]));
+                    const _: () = assert!(![<sort_equal_ $tpe _array>](GENERATED, [0, 1, 2, 2, 3]));
+                    const _: () = assert!([<sort_equal_ $tpe _array>]([], []));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let mut shuffled = random_array;
+                    shuffled.reverse();
+                    shuffled.swap(0, 150);
+                    assert!([<sort_equal_ $tpe _array>](random_array, shuffled));
+                    shuffled[17] = shuffled[18];
+                    assert_eq!(
+                        [<sort_equal_ $tpe _array>](random_array, shuffled),
+                        random_array[282] == random_array[281],
+                    );
+
+                    const _: () = assert!([<into_sorted_equals_ $tpe _array>](GENERATED, &[0, 1, 1, 2, 3]));
+                    const _: () = assert!(![<into_sorted_equals_ $tpe _array>](GENERATED, &GENERATED));
+                    const _: () = assert!(![<into_sorted_equals_ $tpe _array>](GENERATED, &[0, 1, 2, 2, 3]));
+                    const _: () = assert!([<into_sorted_equals_ $tpe _array>]([], &[]));
+                    let mut expected = random_array;
+                    expected.sort();
+                    assert!([<into_sorted_equals_ $tpe _array>](random_array, &expected));
+                }
+            }
+        )+
+    };
+}
+
+test_sort_equal! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_sort_equal_other_types() {
+    const _: () = assert!(sort_equal_bool_array(
+        [true, false, true],
+        [true, true, false]
+    ));
+    const _: () = assert!(!sort_equal_bool_array(
+        [true, false, false],
+        [true, true, false]
+    ));
+    const _: () = assert!(sort_equal_char_array(['a', 'b', 'a'], ['a', 'a', 'b']));
+    const _: () = assert!(!sort_equal_char_array(['a', 'b', 'b'], ['a', 'a', 'b']));
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_equal_floats() {
+    const _: () = assert!(sort_equal_f32_array(
+        [f32::NAN, 0.0, 1.0],
+        [1.0, 0.0, f32::NAN]
+    ));
+    const _: () = assert!(!sort_equal_f32_array([-0.0, 0.0], [0.0, 0.0]));
+    const _: () = assert!(sort_equal_f64_array(
+        [f64::NAN, 0.0, 1.0],
+        [1.0, 0.0, f64::NAN]
+    ));
+    const _: () = assert!(!sort_equal_f64_array([-0.0, 0.0], [0.0, 0.0]));
+    const _: () = assert!(into_sorted_equals_f32_array([0.0, -0.0], &[-0.0, 0.0]));
+    const _: () = assert!(!into_sorted_equals_f64_array([0.0, -0.0], &[0.0, 0.0]));
+}
+
+macro_rules! test_max_run {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_with_max_run_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], usize) = [<into_sorted_with_max_run_ $tpe _array>]([]);
+                    const ALL_DISTINCT: ([$tpe; 4], usize) = [<into_sorted_with_max_run_ $tpe _array>]([3, 0, 2, 1]);
+                    const ALL_EQUAL: ([$tpe; 50], usize) = [<into_sorted_with_max_run_ $tpe _array>]([7; 50]);
+                    const MIXED: ([$tpe; 8], usize) = [<into_sorted_with_max_run_ $tpe _array>]([5, 2, 5, 2, 9, 2, 5, 5]);
+
+                    assert_eq!(EMPTY.1, 0);
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 2, 3], 1));
+                    assert_eq!(ALL_EQUAL, ([7; 50], 50));
+                    assert_eq!(MIXED, ([2, 2, 2, 5, 5, 5, 5, 9], 4));
+                }
+            }
+        )+
+    };
+}
+
+test_max_run! { u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_into_sorted_with_max_run_other_types() {
+    assert_eq!(into_sorted_with_max_run_f32_array([0.0, -0.0, 0.0]).1, 2);
+    assert_eq!(into_sorted_with_max_run_f64_array([0.0, -0.0, 0.0]).1, 2);
+    assert_eq!(into_sorted_with_max_run_char_array(['a', 'b', 'b']).1, 2);
+}
+
+macro_rules! test_sorted_dedup {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_dedup_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], usize) = [<into_sorted_dedup_ $tpe _array>]([]);
+                    const ALL_DISTINCT: ([$tpe; 4], usize) = [<into_sorted_dedup_ $tpe _array>]([3, 0, 2, 1]);
+                    const ALL_EQUAL: ([$tpe; 50], usize) = [<into_sorted_dedup_ $tpe _array>]([7; 50]);
+                    const MIXED: ([$tpe; 8], usize) = [<into_sorted_dedup_ $tpe _array>]([5, 2, 5, 2, 9, 2, 5, $tpe::MAX]);
+
+                    assert_eq!(EMPTY.1, 0);
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 2, 3], 4));
+                    assert_eq!(ALL_EQUAL.1, 1);
+                    assert_eq!(ALL_EQUAL.0[0], 7);
+                    assert_eq!(MIXED.1, 4);
+                    assert_eq!(MIXED.0[..4], [2, 5, 9, $tpe::MAX]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64);
+                    let (deduped, count) = [<into_sorted_dedup_ $tpe _array>](random_array);
+                    let mut expected = random_array.to_vec();
+                    expected.sort();
+                    expected.dedup();
+                    assert_eq!(&deduped[..count], expected);
+                    let mut all = deduped;
+                    all.sort();
+                    assert_eq!(all, [<into_sorted_ $tpe _array>](random_array));
+                }
+            }
+        )+
+    };
+}
+
+test_sorted_dedup! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_distinct_flag {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_distinct_flag_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], bool) = [<into_sorted_distinct_flag_ $tpe _array>]([]);
+                    const SINGLE: ([$tpe; 1], bool) = [<into_sorted_distinct_flag_ $tpe _array>]([$tpe::MAX]);
+                    const ALL_DISTINCT: ([$tpe; 4], bool) = [<into_sorted_distinct_flag_ $tpe _array>]([3, 0, $tpe::MAX, 1]);
+                    const ALL_EQUAL: ([$tpe; 4], bool) = [<into_sorted_distinct_flag_ $tpe _array>]([7; 4]);
+                    const ONE_DUPLICATE: ([$tpe; 5], bool) = [<into_sorted_distinct_flag_ $tpe _array>]([4, 0, 2, 1, 4]);
+
+                    assert_eq!(EMPTY, ([], true));
+                    assert_eq!(SINGLE, ([$tpe::MAX], true));
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 3, $tpe::MAX], true));
+                    assert_eq!(ALL_EQUAL, ([7; 4], false));
+                    assert_eq!(ONE_DUPLICATE, ([0, 1, 2, 4, 4], false));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    for _ in 0..20 {
+                        let random_array: [$tpe; 10] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64);
+                        let (sorted, distinct) = [<into_sorted_distinct_flag_ $tpe _array>](random_array);
+                        assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                        assert_eq!(distinct, [<distinct_count_ $tpe _array>](random_array) == 10);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+test_distinct_flag! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_dup_mask {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_dup_mask_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], [bool; 0]) = [<into_sorted_dup_mask_ $tpe _array>]([]);
+                    const ALL_DISTINCT: ([$tpe; 4], [bool; 4]) = [<into_sorted_dup_mask_ $tpe _array>]([3, 0, $tpe::MAX, 1]);
+                    const ALL_EQUAL: ([$tpe; 4], [bool; 4]) = [<into_sorted_dup_mask_ $tpe _array>]([7; 4]);
+                    const MIXED: ([$tpe; 6], [bool; 6]) = [<into_sorted_dup_mask_ $tpe _array>]([4, 0, 2, 4, 0, 4]);
+
+                    assert_eq!(EMPTY, ([], []));
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 3, $tpe::MAX], [false; 4]));
+                    assert_eq!(ALL_EQUAL, ([7; 4], [false, true, true, true]));
+                    assert_eq!(MIXED, ([0, 0, 2, 4, 4, 4], [false, true, false, false, true, true]));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 64);
+                    let (sorted, duplicates) = [<into_sorted_dup_mask_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    assert!(!duplicates[0]);
+                    for i in 1..300 {
+                        assert_eq!(duplicates[i], sorted[i] == sorted[i - 1]);
+                    }
+                    let unique: Vec<$tpe> = sorted.iter().zip(duplicates).filter(|(_, dup)| !dup).map(|(&x, _)| x).collect();
+                    assert_eq!(unique, [<into_sorted_dedup_ $tpe _array>](random_array).0[..unique.len()]);
+                }
+            }
+        )+
+    };
+}
+
+test_dup_mask! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+macro_rules! test_run_starts {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_into_sorted_run_starts_ $tpe>]() {
+                    const EMPTY: ([$tpe; 0], [usize; 0], usize) = [<into_sorted_run_starts_ $tpe _array>]([]);
+                    const ALL_EQUAL: ([$tpe; 4], [usize; 4], usize) = [<into_sorted_run_starts_ $tpe _array>]([7; 4]);
+                    const ALL_DISTINCT: ([$tpe; 4], [usize; 4], usize) = [<into_sorted_run_starts_ $tpe _array>]([3, 0, $tpe::MAX, 1]);
+                    const MIXED: ([$tpe; 6], [usize; 6], usize) = [<into_sorted_run_starts_ $tpe _array>]([4, 0, 2, 4, 0, 4]);
+
+                    assert_eq!(EMPTY, ([], [], 0));
+                    assert_eq!(ALL_EQUAL, ([7; 4], [0; 4], 1));
+                    assert_eq!(ALL_DISTINCT, ([0, 1, 3, $tpe::MAX], [0, 1, 2, 3], 4));
+                    assert_eq!(MIXED, ([0, 0, 2, 4, 4, 4], [0, 2, 3, 0, 0, 0], 3));
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen::<u8>() as $tpe % 32);
+                    let (sorted, starts, count) = [<into_sorted_run_starts_ $tpe _array>](random_array);
+                    assert_eq!(sorted, [<into_sorted_ $tpe _array>](random_array));
+                    let mut ends = starts[1..count].to_vec();
+                    ends.push(300);
+                    let groups: Vec<&[$tpe]> = starts[..count].iter().zip(ends).map(|(&start, end)| &sorted[start..end]).collect();
+                    assert_eq!(groups, sorted.chunk_by(|a, b| a == b).collect::<Vec<_>>());
+                }
+            }
+        )+
+    };
+}
+
+test_run_starts! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[test]
+fn test_into_sorted_run_starts_char() {
+    assert_eq!(
+        into_sorted_run_starts_char_array(['b', 'a', 'b']),
+        (['a', 'b', 'b'], [0, 1, 0], 2)
+    );
+}
+
+#[test]
+fn test_into_sorted_dup_mask_char() {
+    assert_eq!(
+        into_sorted_dup_mask_char_array(['b', 'a', 'b']),
+        (['a', 'b', 'b'], [false, false, true])
+    );
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_into_sorted_distinct_flag_floats() {
+    // -0.0 and 0.0 are distinct under the total order.
+    assert!(into_sorted_distinct_flag_f32_array([0.0, -0.0, 1.0]).1);
+    assert!(!into_sorted_distinct_flag_f32_array([0.0, 1.0, 0.0]).1);
+    assert!(!into_sorted_distinct_flag_f64_array([f64::NAN, f64::NAN]).1);
+    assert_eq!(
+        into_sorted_distinct_flag_char_array(['b', 'a']),
+        (['a', 'b'], true)
+    );
+}
+
+#[test]
+fn test_into_sorted_dedup_timestamps() {
+    const TIMESTAMPS: ([i64; 6], usize) = into_sorted_dedup_i64_array([
+        1_700_000_060,
+        1_700_000_000,
+        1_700_000_060,
+        -86_400,
+        1_700_000_000,
+        1_700_000_120,
+    ]);
+    assert_eq!(TIMESTAMPS.1, 4);
+    assert_eq!(
+        TIMESTAMPS.0[..4],
+        [-86_400, 1_700_000_000, 1_700_000_060, 1_700_000_120]
+    );
+    assert_eq!(into_sorted_dedup_char_array(['b', 'a', 'b']).1, 2);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_into_sorted_dedup_floats() {
+    // -0.0 and 0.0 are distinct under the total order.
+    let (deduped, count) = into_sorted_dedup_f32_array([0.0, -0.0, 1.0, 1.0, 0.0]);
+    assert_eq!(count, 3);
+    assert_eq!(
+        deduped[..count]
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<Vec<_>>(),
+        [-0.0_f32, 0.0, 1.0].map(f32::to_bits)
+    );
+    assert_eq!(into_sorted_dedup_f64_array([f64::NAN, f64::NAN]).1, 1);
+}
+
+#[test]
+fn test_distinct_count_char() {
+    assert_eq!(distinct_count_char_array(['a', 'b', 'a', 'c', 'b']), 3);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_distinct_count_floats() {
+    // -0.0 and 0.0 are distinct under the total order.
+    assert_eq!(distinct_count_f32_array([0.0, -0.0, 1.0, 1.0, f32::NAN]), 4);
+    assert_eq!(distinct_count_f64_array([0.0, -0.0, 1.0, 1.0, f64::NAN]), 4);
+}
+
+#[test]
+fn test_rank_char() {
+    assert_eq!(rank_char_array(&['a', 'b', 'b', 'c'], 'b'), (1, 2));
+    assert_eq!(rank_char_array(&['a', 'b', 'b', 'c'], 'd'), (4, 0));
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_rank_floats() {
+    const SORTED_F32: [f32; 5] = [f32::NEG_INFINITY, -0.0, 0.0, 0.0, f32::NAN];
+    const SORTED_F64: [f64; 5] = [f64::NEG_INFINITY, -0.0, 0.0, 0.0, f64::NAN];
+
+    assert_eq!(rank_f32_array(&SORTED_F32, 0.0), (2, 2));
+    assert_eq!(rank_f32_array(&SORTED_F32, -0.0), (1, 1));
+    assert_eq!(rank_f32_array(&SORTED_F32, f32::NAN), (4, 1));
+    assert_eq!(rank_f64_array(&SORTED_F64, 0.0), (2, 2));
+    assert_eq!(rank_f64_array(&SORTED_F64, -0.0), (1, 1));
+    assert_eq!(rank_f64_array(&SORTED_F64, 1.0), (4, 0));
+
+    assert!(sorted_contains_f32_array(&SORTED_F32, f32::NAN));
+    assert!(sorted_contains_f32_array(&SORTED_F32, -0.0));
+    assert!(!sorted_contains_f64_array(&SORTED_F64, 1.0));
+    assert!(!sorted_contains_f64_array(&[-0.0], 0.0));
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_equal_range_floats() {
+    const SORTED_F32: [f32; 5] = [f32::NEG_INFINITY, -0.0, 0.0, 0.0, f32::NAN];
+    assert_eq!(equal_range_f32_array(&SORTED_F32, 0.0), (2, 4));
+    assert_eq!(equal_range_f32_array(&SORTED_F32, -0.0), (1, 2));
+    assert_eq!(equal_range_f32_array(&SORTED_F32, f32::NAN), (4, 5));
+    assert_eq!(equal_range_f64_array(&[1.0, 1.0, 1.0], 1.0), (0, 3));
+    assert_eq!(equal_range_f64_array(&[1.0, 3.0], 2.0), (1, 1));
+}
+
+#[test]
+fn test_equal_range_char() {
+    assert_eq!(equal_range_char_array(&['a', 'b', 'b', 'c'], 'b'), (1, 3));
+}
+
+#[test]
+fn test_try_select_nth_char() {
+    const MIDDLE: Result<char, SortError> = try_select_nth_char_array(['c', 'a', 'b'], 1);
+
+    assert_eq!(MIDDLE, Ok('b'));
+    assert_eq!(
+        try_select_nth_char_array(['c', 'a', 'b'], 3),
+        Err(SortError::IndexOutOfRange)
+    );
+}
+
+test_signed_integer! { i8, i16, i32, i64, i128, isize }
+
+#[rustversion::since(1.83.0)]
+quickcheck_slice_sort! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize,
+    char,
+    bool
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_sorted_array_macro() {
+    use compile_time_sort::sorted_array;
+
+    const NEGATIVE_ONE: i64 = -1;
+
+    assert_eq!(sorted_array!(i32; [3, 1, 2]), [1, 2, 3]);
+    assert_eq!(
+        sorted_array!(u8; [3, 1, 2, u8::MAX, 0,]),
+        [0, 1, 2, 3, u8::MAX]
+    );
+    assert_eq!(
+        sorted_array!(i64; [0, NEGATIVE_ONE, i64::MIN]),
+        [i64::MIN, -1, 0]
+    );
+    assert_eq!(sorted_array!(bool; [true, false]), [false, true]);
+    assert_eq!(sorted_array!(char; ['c', 'a', 'b']), ['a', 'b', 'c']);
+    assert_eq!(sorted_array!(usize; []), []);
+}
+
+#[cfg(feature = "macros")]
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sorted_array_macro_floats() {
+    use compile_time_sort::sorted_array;
+
+    assert_eq!(sorted_array!(f32; [1.0, -0.0, 0.5]), [-0.0, 0.5, 1.0]);
+    assert_eq!(
+        sorted_array!(f64; [1.0, f64::NEG_INFINITY, 0.5]),
+        [f64::NEG_INFINITY, 0.5, 1.0]
+    );
+}
+
+#[cfg(feature = "nested")]
+#[test]
+fn test_sort_bool_slice_arrays() {
+    const ARR: [&[bool]; 3] = [&[false, false], &[true], &[true, true]];
+    const SORTED_ARR: [&[bool]; 3] = into_sorted_bool_slice_array(ARR);
+
+    assert!(SORTED_ARR.is_sorted());
+}
+
+#[cfg(feature = "nested")]
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_bool_slice_slices() {
+    const SORTED_ARR: [&[bool]; 3] = {
+        let mut arr: [&[bool]; 3] = [&[false], &[true], &[true, true]];
+        sort_bool_slice_slice(&mut arr);
+        arr
+    };
+
+    assert!(SORTED_ARR.is_sorted());
+}
+
+macro_rules! test_unsigned_slices {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_ $tpe _slice_array>]() {
+                    const ARR: [&[$tpe]; 4] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1]];
+                    const SORTED_ARR: [&[$tpe]; 4] = [<into_sorted_ $tpe _slice_array>](ARR);
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+
+                #[test]
+                fn [<test_sort_long_identical_ $tpe _slice_array>]() {
+                    const ARR: [&[$tpe]; 500] = [&[0]; 500];
+                    const SORTED_ARR: [&[$tpe]; 500] = [<into_sorted_ $tpe _slice_array>](ARR);
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+
+                #[rustversion::since(1.83.0)]
+                #[test]
+                fn [<test_sort_ $tpe _slice_slice>]() {
+                     const SORTED_ARR: [&[$tpe]; 4] = {
+                        let mut arr: [&[$tpe]; 4] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1]];
+                        [<sort_ $tpe _slice_slice>](&mut arr);
+                        arr
+                    };
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+
+                #[rustversion::since(1.83.0)]
+                #[test]
+                fn [<test_sort_long_identical_ $tpe _slice_slice>]() {
+                    const SORTED_ARR: [&[$tpe]; 500] = {
+                        let mut arr: [&[$tpe]; 500] = [&[]; 500];
+                        [<sort_ $tpe _slice_slice>](&mut arr);
+                        arr
+                    };
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "nested")]
+macro_rules! test_signed_slices {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_sort_ $tpe _slice_array_with_negatives>]() {
+                    const ARR: [&[$tpe]; 8] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1], &[0, -1], &[0, 0], &[-1, 0], &[-1, -1]];
+                    const SORTED_ARR: [&[$tpe]; 8] = [<into_sorted_ $tpe _slice_array>](ARR);
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+
+                #[rustversion::since(1.83.0)]
+                #[test]
+                fn [<test_sort_ $tpe _slice_slice_with_negatives>]() {
+                     const SORTED_ARR: [&[$tpe]; 8] = {
+                        let mut arr: [&[$tpe]; 8] = [&[0, 1], &[0, 0], &[1, 0], &[1, 1], &[0, -1], &[0, 0], &[-1, 0], &[-1, -1]];
+                        [<sort_ $tpe _slice_slice>](&mut arr);
+                        arr
+                    };
+
+                    assert!(SORTED_ARR.is_sorted());
+                }
+            }
+        )+
+
+        test_unsigned_slices! { $($tpe),+ }
+    };
+}
+
+test_unsigned_slices! { u8 }
+
+#[cfg(feature = "nested")]
+test_unsigned_slices! {
+    u16, u32, u64, u128, usize
+}
+
+#[cfg(feature = "nested")]
+test_signed_slices! {
+    i8, i16, i32, i64, i128, isize
+}
+
+#[rustversion::since(1.83.0)]
+macro_rules! test_write_sorted {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_write_sorted_ $tpe>]() {
+                    static INPUT: [$tpe; 5] = [3, 1, 4, 1, 5];
+                    const INPUT_CONST: [$tpe; 5] = [3, 1, 4, 1, 5];
+                    const OUTPUT: [$tpe; 5] = {
+                        let mut output = [0; 5];
+                        [<write_sorted_ $tpe _array>](&INPUT_CONST, &mut output);
+                        output
+                    };
+
+                    assert_eq!(OUTPUT, [1, 1, 3, 4, 5]);
+
+                    let mut output = [0; 5];
+                    [<write_sorted_ $tpe _array>](&INPUT, &mut output);
+                    assert_eq!(output, [1, 1, 3, 4, 5]);
+                    assert_eq!(INPUT, [3, 1, 4, 1, 5]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let input = random_array;
+                    let mut output = [0; 300];
+                    [<write_sorted_ $tpe _array>](&input, &mut output);
+                    assert_eq!(input, random_array);
+                    assert_eq!(output, [<into_sorted_ $tpe _array>](random_array));
+                }
+
+                #[test]
+                fn [<test_sort_ $tpe _array_ref>]() {
+                    const SORTED: [$tpe; 5] = {
+                        let mut array = [3, 1, 4, 1, 5];
+                        [<sort_ $tpe _array_ref>](&mut array);
+                        array
+                    };
+                    assert_eq!(SORTED, [1, 1, 3, 4, 5]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let random_array: [$tpe; 300] = core::array::from_fn(|_| rng.gen());
+                    let mut array = random_array;
+                    [<sort_ $tpe _array_ref>](&mut array);
+                    assert_eq!(array, [<into_sorted_ $tpe _array>](random_array));
+                }
+            }
+        )+
+    };
+}
+
+#[rustversion::since(1.83.0)]
+macro_rules! test_merge_stable {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_merge_stable_ $tpe>]() {
+                    const MERGED: [$tpe; 7] = {
+                        let mut out = [0; 7];
+                        [<merge_stable_ $tpe _arrays>]([0, 2, 2, 9], [1, 2, 3], &mut out);
+                        out
+                    };
+                    assert_eq!(MERGED, [0, 1, 2, 2, 2, 3, 9]);
+
+                    let mut out = [0; 3];
+                    [<merge_stable_ $tpe _arrays>]([], [1, 2, 3], &mut out);
+                    assert_eq!(out, [1, 2, 3]);
+                    [<merge_stable_ $tpe _arrays>]([4, 5, 6], [], &mut out);
+                    assert_eq!(out, [4, 5, 6]);
+
+                    let mut out = [0; 6];
+                    [<merge_stable_ $tpe _arrays>]([0, 2, 4], [1, 3, 5], &mut out);
+                    assert_eq!(out, [0, 1, 2, 3, 4, 5]);
+                    [<merge_stable_ $tpe _arrays>]([3, 4, 5], [0, 1, 2], &mut out);
+                    assert_eq!(out, [0, 1, 2, 3, 4, 5]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let a: [$tpe; 150] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen()));
+                    let b: [$tpe; 100] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen()));
+                    let mut out = [0; 250];
+                    [<merge_stable_ $tpe _arrays>](a, b, &mut out);
+                    let mut expected = [a.as_slice(), b.as_slice()].concat();
+                    expected.sort();
+                    assert_eq!(out.as_slice(), expected);
+                }
+            }
+        )+
+    };
+}
+
+#[rustversion::since(1.83.0)]
+test_merge_stable! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_merge_stable_other_types() {
+    let mut out = [0.0; 5];
+    merge_stable_f32_arrays([-0.0, 1.0], [f32::NEG_INFINITY, 0.0, 1.0], &mut out);
+    assert_eq!(
+        out.map(f32::to_bits),
+        [f32::NEG_INFINITY, -0.0, 0.0, 1.0, 1.0].map(f32::to_bits)
+    );
+
+    let mut out = ['\0'; 4];
+    merge_stable_char_arrays(['a', 'c'], ['b', 'c'], &mut out);
+    assert_eq!(out, ['a', 'b', 'c', 'c']);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+#[should_panic]
+fn test_merge_stable_wrong_output_length() {
+    let mut out = [0; 4];
+    merge_stable_i32_arrays([1, 2], [3], &mut out);
+}
+
+#[rustversion::since(1.83.0)]
+macro_rules! test_merge_dedup {
+    ($($tpe:ty),+) => {
+        $(
+            paste! {
+                #[test]
+                fn [<test_merge_dedup_ $tpe>]() {
+                    const MERGED: ([$tpe; 7], usize) = {
+                        let mut out = [0; 7];
+                        let count = [<merge_dedup_ $tpe _arrays>]([0, 2, 2, 9], [1, 2, 3], &mut out);
+                        (out, count)
+                    };
+                    assert_eq!(MERGED.1, 5);
+                    assert_eq!(MERGED.0[..MERGED.1], [0, 1, 2, 3, 9]);
+
+                    let mut out = [0; 4];
+                    assert_eq!([<merge_dedup_ $tpe _arrays>]([], [1, 1, 2, 3], &mut out), 3);
+                    assert_eq!(out[..3], [1, 2, 3]);
+                    assert_eq!([<merge_dedup_ $tpe _arrays>]([4, 5, 5, 6], [], &mut out), 3);
+                    assert_eq!(out[..3], [4, 5, 6]);
+                    assert_eq!([<merge_dedup_ $tpe _arrays>]([7, 7], [7, 7], &mut out), 1);
+                    assert_eq!(out[..1], [7]);
+                    assert_eq!([<merge_dedup_ $tpe _arrays>]::<0, 0>([], [], &mut out), 0);
+
+                    let mut out = [0; 8];
+                    assert_eq!([<merge_dedup_ $tpe _arrays>]([0, 1, 2, 3], [2, 3, 4, 5], &mut out), 6);
+                    assert_eq!(out[..6], [0, 1, 2, 3, 4, 5]);
+
+                    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+                    let a: [$tpe; 150] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen::<$tpe>() % 50));
+                    let b: [$tpe; 100] = [<into_sorted_ $tpe _array>](core::array::from_fn(|_| rng.gen::<$tpe>() % 50));
+                    let mut out = [0; 250];
+                    let count = [<merge_dedup_ $tpe _arrays>](a, b, &mut out);
+                    let mut expected = [a.as_slice(), b.as_slice()].concat();
+                    expected.sort();
+                    expected.dedup();
+                    assert_eq!(&out[..count], expected);
+                }
+            }
+        )+
+    };
+}
+
+#[rustversion::since(1.83.0)]
+test_merge_dedup! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_merge_dedup_other_types() {
+    let mut out = [0.0; 5];
+    let count = merge_dedup_f32_arrays([-0.0, 1.0], [f32::NEG_INFINITY, 0.0, 1.0], &mut out);
+    assert_eq!(
+        out[..count].iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        [f32::NEG_INFINITY, -0.0, 0.0, 1.0].map(f32::to_bits)
+    );
+
+    let mut out = ['\0'; 4];
+    let count = merge_dedup_char_arrays(['a', 'c'], ['b', 'c'], &mut out);
+    assert_eq!(out[..count], ['a', 'b', 'c']);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+#[should_panic]
+fn test_merge_dedup_short_output() {
+    let mut out = [0; 2];
+    merge_dedup_i32_arrays([1, 2], [2], &mut out);
+}
+
+#[rustversion::since(1.83.0)]
+test_write_sorted! { u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_write_sorted_other_types() {
+    let mut bools = [false; 3];
+    write_sorted_bool_array(&[true, false, true], &mut bools);
+    assert_eq!(bools, [false, true, true]);
+
+    let mut chars = ['\0'; 3];
+    write_sorted_char_array(&['c', 'a', 'b'], &mut chars);
+    assert_eq!(chars, ['a', 'b', 'c']);
+
+    let mut f32s = [0.0; 3];
+    write_sorted_f32_array(&[1.0, -0.0, f32::NEG_INFINITY], &mut f32s);
+    assert_eq!(f32s, [f32::NEG_INFINITY, -0.0, 1.0]);
+
+    let mut f64s = [0.0; 3];
+    write_sorted_f64_array(&[1.0, -0.0, f64::NEG_INFINITY], &mut f64s);
+    assert_eq!(f64s, [f64::NEG_INFINITY, -0.0, 1.0]);
+
+    let mut bools = [true, false, true];
+    sort_bool_array_ref(&mut bools);
+    assert_eq!(bools, [false, true, true]);
+
+    let mut chars = ['c', 'a', 'b'];
+    sort_char_array_ref(&mut chars);
+    assert_eq!(chars, ['a', 'b', 'c']);
+
+    let mut f32s = [1.0, -0.0, f32::NEG_INFINITY];
+    sort_f32_array_ref(&mut f32s);
+    assert_eq!(f32s, [f32::NEG_INFINITY, -0.0, 1.0]);
+
+    let mut f64s = [1.0, -0.0, f64::NEG_INFINITY];
+    sort_f64_array_ref(&mut f64s);
+    assert_eq!(f64s, [f64::NEG_INFINITY, -0.0, 1.0]);
+}
+
+#[test]
+fn test_sort_str_array() {
+    const ARR: [&str; 4] = ["abc", "abd", "aaaaa", "l"];
+    const SORTED_ARR: [&str; 4] = into_sorted_str_array(ARR);
+
+    assert!(SORTED_ARR.is_sorted());
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_large_reverse_sorted_slice() {
+    const SORTED_ARR: [i32; 4000] = {
+        let mut arr = [0; 4000];
+        let mut i = 0;
+        while i < arr.len() {
+            arr[i] = (arr.len() - i) as i32;
+            i += 1;
+        }
+        sort_i32_slice(&mut arr);
+        arr
+    };
+
+    assert!(SORTED_ARR.is_sorted());
+    assert_eq!(SORTED_ARR[0], 1);
+    assert_eq!(SORTED_ARR[3999], 4000);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_reverse_sorted_slice_with_duplicates() {
+    const SORTED_ARR: [i32; 10] = {
+        let mut arr = [9, 9, 7, 5, 5, 5, 3, 0, -2, -2];
+        sort_i32_slice(&mut arr);
+        arr
+    };
+    assert_eq!(SORTED_ARR, [-2, -2, 0, 3, 5, 5, 5, 7, 9, 9]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut random_vec: Vec<u64> = (0..1000).map(|_| rng.gen_range(0..50)).collect();
+    random_vec.sort_by(|a, b| b.cmp(a));
+    let mut expected = random_vec.clone();
+    expected.sort();
+    sort_u64_slice(&mut random_vec);
+    assert_eq!(random_vec, expected);
+
+    let mut floats = [1.0, 0.0, -0.0, -0.0, f32::NEG_INFINITY];
+    sort_f32_slice(&mut floats);
+    assert_eq!(
+        floats.map(f32::to_bits),
+        [f32::NEG_INFINITY, -0.0, -0.0, 0.0, 1.0].map(f32::to_bits)
+    );
+}
+
+/// Returns an array of the numbers 0, 1, ..., N - 1 in order.
+const fn sorted_input<const N: usize>() -> [i32; N] {
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = i as i32;
+        i += 1;
+    }
+    arr
+}
+
+/// Returns an array that increases up to its middle and then decreases again.
+const fn organ_pipe_input<const N: usize>() -> [i32; N] {
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = if i < N / 2 { i } else { N - i } as i32;
+        i += 1;
+    }
+    arr
+}
+
+// These inputs are the worst cases of some pivot choices of plain quicksort,
+// and sorting them should not come close to the limits of `const` evaluation.
+
+#[test]
+fn test_sort_large_sorted_array() {
+    static SORTED_ARR: [i32; 5000] = into_sorted_i32_array(sorted_input());
+
+    assert_eq!(SORTED_ARR, sorted_input());
+}
+
+#[test]
+fn test_sort_two_elements() {
+    const SWAPPED: [i32; 2] = into_sorted_i32_array([2, 1]);
+    const SORTED: [i32; 2] = into_sorted_i32_array([1, 2]);
+    const EQUAL: [i32; 2] = into_sorted_i32_array([1, 1]);
+    const EXTREMES: [i32; 2] = into_sorted_i32_array([i32::MAX, i32::MIN]);
+
+    assert_eq!(SWAPPED, [1, 2]);
+    assert_eq!(SORTED, [1, 2]);
+    assert_eq!(EQUAL, [1, 1]);
+    assert_eq!(EXTREMES, [i32::MIN, i32::MAX]);
+    assert_eq!(into_sorted_char_array(['b', 'a']), ['a', 'b']);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_two_element_slices() {
+    const SWAPPED: [i32; 2] = {
+        let mut arr = [2, 1];
+        sort_i32_slice(&mut arr);
+        arr
+    };
+    let mut extremes = [f64::INFINITY, f64::NEG_INFINITY];
+    sort_f64_slice(&mut extremes);
+
+    assert_eq!(SWAPPED, [1, 2]);
+    assert_eq!(extremes, [f64::NEG_INFINITY, f64::INFINITY]);
+}
+
+#[test]
+fn test_sort_monotone_arrays() {
+    const REVERSE_WITH_DUPLICATES: [i32; 8] = into_sorted_i32_array([9, 7, 7, 5, 5, 5, 0, -3]);
+    const SORTED_WITH_DUPLICATES: [i32; 6] = into_sorted_i32_array([-3, 0, 0, 1, 1, 9]);
+    const ALL_EQUAL: [i32; 4] = into_sorted_i32_array([2; 4]);
+    const NEARLY_REVERSE: [i32; 6] = into_sorted_i32_array([9, 7, 8, 5, 5, 0]);
+
+    assert_eq!(REVERSE_WITH_DUPLICATES, [-3, 0, 5, 5, 5, 7, 7, 9]);
+    assert_eq!(SORTED_WITH_DUPLICATES, [-3, 0, 0, 1, 1, 9]);
+    assert_eq!(ALL_EQUAL, [2; 4]);
+    assert_eq!(NEARLY_REVERSE, [0, 5, 5, 7, 8, 9]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut random_array: [i32; 500] = core::array::from_fn(|_| rng.gen_range(-20..20));
+    random_array.sort();
+    random_array.reverse();
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_i32_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_large_organ_pipe_array() {
+    static SORTED_ARR: [i32; 5000] = into_sorted_i32_array(organ_pipe_input());
+
+    let mut expected = organ_pipe_input::<5000>();
+    expected.sort();
+    assert_eq!(SORTED_ARR, expected);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_large_sorted_and_organ_pipe_slices() {
+    static SORTED_ARR: [i32; 5000] = {
+        let mut arr = sorted_input();
+        sort_i32_slice(&mut arr);
+        arr
+    };
+    static SORTED_ORGAN_PIPE_ARR: [i32; 5000] = {
+        let mut arr = organ_pipe_input();
+        sort_i32_slice(&mut arr);
+        arr
+    };
+
+    assert_eq!(SORTED_ARR, sorted_input());
+    let mut expected = organ_pipe_input::<5000>();
+    expected.sort();
+    assert_eq!(SORTED_ORGAN_PIPE_ARR, expected);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_str_slice() {
+    const SORTED_ARR: [&str; 4] = {
+        let mut arr: [&str; 4] = ["abc", "abd", "aaaaa", "l"];
+        sort_str_slice(&mut arr);
+        arr
+    };
+
+    assert!(SORTED_ARR.is_sorted());
+}
+
+#[test]
+fn test_sort_bool() {
+    const ARR: [bool; 4] = [false, true, false, true];
+    const SORTED_ARR: [bool; 4] = into_sorted_bool_array(ARR);
+
+    assert_eq!(SORTED_ARR, [false, false, true, true]);
+}
+
+#[test]
+fn test_sort_bool_with_split() {
+    const MIXED: ([bool; 5], usize) =
+        into_sorted_bool_array_with_split([true, false, true, false, false]);
+    const ALL_TRUE: ([bool; 3], usize) = into_sorted_bool_array_with_split([true; 3]);
+    const ALL_FALSE: ([bool; 3], usize) = into_sorted_bool_array_with_split([false; 3]);
+    const EMPTY: ([bool; 0], usize) = into_sorted_bool_array_with_split([]);
+
+    assert_eq!(MIXED, ([false, false, false, true, true], 3));
+    assert_eq!(ALL_TRUE, ([true; 3], 0));
+    assert_eq!(ALL_FALSE, ([false; 3], 3));
+    assert_eq!(EMPTY, ([], 0));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [bool; 300] = core::array::from_fn(|_| rng.gen());
+    let (sorted, split) = into_sorted_bool_array_with_split(random_array);
+    assert_eq!(sorted, into_sorted_bool_array(random_array));
+    assert_eq!(split, random_array.iter().filter(|&&b| !b).count());
+}
+
+#[test]
+fn test_sort_bool_desc() {
+    const SORTED_ARR: [bool; 5] = into_sorted_bool_array_desc([false, true, false, true, false]);
+    assert_eq!(SORTED_ARR, [true, true, false, false, false]);
+    assert_eq!(into_sorted_bool_array_desc([true; 2]), [true; 2]);
+    assert_eq!(into_sorted_bool_array_desc([false; 2]), [false; 2]);
+    assert_eq!(into_sorted_bool_array_desc([]), [false; 0]);
+}
+
+#[test]
+fn test_sort_u8_pairs() {
+    const SMALL: [(u8, u8); 4] = into_sorted_u8_pair_array([(1, 0), (0, 255), (0, 1), (1, 0)]);
+    assert_eq!(SMALL, [(0, 1), (0, 255), (1, 0), (1, 0)]);
+
+    const ALL_EQUAL: [(u8, u8); 100] = into_sorted_u8_pair_array([(7, 7); 100]);
+    assert_eq!(ALL_EQUAL, [(7, 7); 100]);
+
+    const SECOND_BYTE_ONLY: [(u8, u8); 20] = {
+        let mut arr = [(3, 0); 20];
+        let mut i = 0;
+        while i < arr.len() {
+            arr[i].1 = (arr.len() - i) as u8;
+            i += 1;
+        }
+        into_sorted_u8_pair_array(arr)
+    };
+    assert!(SECOND_BYTE_ONLY.is_sorted());
+    assert!(SECOND_BYTE_ONLY.iter().all(|&(a, _)| a == 3));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [(u8, u8); 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_u8_pair_array(random_array), expected);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+enum Priority {
+    Low = 0,
+    Mid = 1,
+    High = 2,
+    Critical = 200,
+}
+
+compile_time_sort::impl_sort_for_repr_u8_enum!(Priority);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i8)]
+enum Signed {
+    Negative = -1,
+    Positive = 1,
+}
+
+// The macro only checks the size of the enum, so this compiles.
+compile_time_sort::impl_sort_for_repr_u8_enum!(Signed);
+
+#[test]
+fn test_sort_repr_i8_enum_without_negative_discriminants() {
+    assert_eq!(
+        Signed::into_sorted_array([Signed::Positive; 2]),
+        [Signed::Positive; 2]
+    );
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_sort_repr_i8_enum_with_negative_discriminant() {
+    Signed::into_sorted_array([Signed::Positive, Signed::Negative]);
+}
+
+#[test]
+fn test_sort_repr_u8_enum() {
+    const SORTED_ARR: [Priority; 6] = Priority::into_sorted_array([
+        Priority::Critical,
+        Priority::High,
+        Priority::Low,
+        Priority::Mid,
+        Priority::Low,
+        Priority::High,
+    ]);
+
+    assert_eq!(
+        SORTED_ARR,
+        [
+            Priority::Low,
+            Priority::Low,
+            Priority::Mid,
+            Priority::High,
+            Priority::High,
+            Priority::Critical
+        ]
+    );
+
+    const EMPTY: [Priority; 0] = Priority::into_sorted_array([]);
+    assert!(EMPTY.is_empty());
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let variants = [
+        Priority::Low,
+        Priority::Mid,
+        Priority::High,
+        Priority::Critical,
+    ];
+    let random_array: [Priority; 500] = core::array::from_fn(|_| variants[rng.gen_range(0..4)]);
+    assert!(Priority::into_sorted_array(random_array).is_sorted());
+}
+
+#[test]
+fn test_sort_u32_by_swap_bytes() {
+    const SORTED_ARR: [u32; 4] =
+        into_sorted_u32_array_by_swap_bytes([0x0000_0001, 0x0100_0000, 0xFF00_0000, 0x0000_0100]);
+    assert_eq!(
+        SORTED_ARR,
+        [0x0100_0000, 0xFF00_0000, 0x0000_0100, 0x0000_0001]
+    );
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u32; 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort_by_key(|x| x.swap_bytes());
+    assert_eq!(into_sorted_u32_array_by_swap_bytes(random_array), expected);
+}
+
+#[test]
+fn test_sort_records_by_keyrange() {
+    // Six-byte records of a two-byte prefix, a big-endian `u16` key, and a two-byte payload.
+    const RECORDS: [[u8; 6]; 5] = [
+        [1, 1, 0, 9, 0xA, 0xA],
+        [2, 2, 0, 3, 0xB, 0xB],
+        [3, 3, 1, 0, 0xC, 0xC],
+        [4, 4, 0, 3, 0xD, 0xD],
+        [5, 5, 0, 0, 0xE, 0xE],
+    ];
+    const BY_KEY: [[u8; 6]; 5] = into_sorted_records_by_keyrange(RECORDS, 2, 2);
+    // The records with equal keys keep their order, and the bytes outside the key move with them.
+    assert_eq!(
+        BY_KEY,
+        [
+            [5, 5, 0, 0, 0xE, 0xE],
+            [2, 2, 0, 3, 0xB, 0xB],
+            [4, 4, 0, 3, 0xD, 0xD],
+            [1, 1, 0, 9, 0xA, 0xA],
+            [3, 3, 1, 0, 0xC, 0xC],
+        ]
+    );
+
+    // An empty key leaves the records in their original order.
+    assert_eq!(into_sorted_records_by_keyrange(RECORDS, 6, 0), RECORDS);
+    assert!(into_sorted_records_by_keyrange::<6, 0>([], 0, 6).is_empty());
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let records: [[u8; 6]; 300] = core::array::from_fn(|_| {
+        let mut record = [0; 6];
+        rng.fill(&mut record);
+        record[1] %= 4;
+        record
+    });
+    let mut expected = records;
+    expected.sort_by_key(|record| [record[1], record[2]]);
+    assert_eq!(into_sorted_records_by_keyrange(records, 1, 2), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_sort_records_by_keyrange_out_of_bounds() {
+    into_sorted_records_by_keyrange([[0_u8; 6]; 2], 5, 2);
+}
+
+#[test]
+fn test_sort_u32_by_popcount() {
+    const SORTED_ARR: [u32; 5] = into_sorted_u32_array_by_popcount([3, 1, 7, 0, 2]);
+    assert_eq!(SORTED_ARR, [0, 1, 2, 3, 7]);
+    assert_eq!(
+        into_sorted_u32_array_by_popcount([u32::MAX, 1 << 31, 0b1010, 0b0110, 1]),
+        [1, 1 << 31, 0b0110, 0b1010, u32::MAX]
+    );
+    assert_eq!(into_sorted_u32_array_by_popcount([]), []);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u32; 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort_by_key(|&x| (x.count_ones(), x));
+    assert_eq!(into_sorted_u32_array_by_popcount(random_array), expected);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_u32_slice_by_swap_bytes() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut random_vec: Vec<u32> = (0..500).map(|_| rng.gen()).collect();
+    let mut expected = random_vec.clone();
+    expected.sort_by_key(|x| x.swap_bytes());
+    sort_u32_slice_by_swap_bytes(&mut random_vec);
+    assert_eq!(random_vec, expected);
+}
+
+#[test]
+fn test_sort_char_array_stable() {
+    const CHARS: [char; 6] = ['b', 'a', 'c', 'a', 'b', 'a'];
+    const MARKERS: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    const PERMUTATION: [usize; 6] = argsort_char_array_stable(&CHARS);
+    const SORTED_CHARS: [char; 6] = into_sorted_char_array_stable(CHARS);
+
+    assert_eq!(SORTED_CHARS, ['a', 'a', 'a', 'b', 'b', 'c']);
+
+    let sorted_markers: Vec<usize> = PERMUTATION.iter().map(|&i| MARKERS[i]).collect();
+    assert_eq!(sorted_markers, [1, 3, 5, 0, 4, 2]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [char; 500] =
+        core::array::from_fn(|_| char::from(rng.gen_range(b'a'..=b'e')));
+    let permutation = argsort_char_array_stable(&random_array);
+    let mut expected: Vec<(char, usize)> = random_array.iter().copied().zip(0..).collect();
+    expected.sort_by_key(|&(c, _)| c);
+    let got: Vec<(char, usize)> = permutation.iter().map(|&i| (random_array[i], i)).collect();
+    assert_eq!(got, expected);
+    assert_eq!(
+        into_sorted_char_array_stable(random_array),
+        into_sorted_char_array(random_array)
+    );
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_floats_by_abs() {
+    const SORTED_F32: [f32; 5] = into_sorted_f32_array_by_abs([-1.0, 1.0, -0.5, 0.5, 0.0]);
+    const SORTED_F64: [f64; 6] = into_sorted_f64_array_by_abs([1.0, -1.0, 0.5, -0.5, 0.0, -0.0]);
+
+    assert_eq!(SORTED_F32, [0.0, -0.5, 0.5, -1.0, 1.0]);
+    assert_eq!(
+        SORTED_F64.map(f64::to_bits),
+        [-0.0, 0.0, -0.5, 0.5, -1.0, 1.0].map(f64::to_bits)
+    );
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut random_array: [f32; 500] = core::array::from_fn(|_| rng.gen_range(-10.0..10.0));
+    random_array[0] = f32::NAN;
+    random_array[1] = -f32::NAN;
+    random_array[2] = f32::INFINITY;
+    random_array[3] = f32::NEG_INFINITY;
+    let mut expected = random_array;
+    expected.sort_by(|a, b| a.abs().total_cmp(&b.abs()).then(a.total_cmp(b)));
+    assert_eq!(
+        into_sorted_f32_array_by_abs(random_array).map(f32::to_bits),
+        expected.map(f32::to_bits)
+    );
+}
+
+#[test]
+fn test_sort_wrapping_i32() {
+    use core::num::Wrapping;
+
+    const SORTED_ARR: [Wrapping<i32>; 4] = into_sorted_wrapping_i32_array([
+        Wrapping(0),
+        Wrapping(i32::MIN),
+        Wrapping(-1),
+        Wrapping(i32::MAX),
+    ]);
+    assert_eq!(
+        SORTED_ARR,
+        [
+            Wrapping(i32::MIN),
+            Wrapping(-1),
+            Wrapping(0),
+            Wrapping(i32::MAX)
+        ]
+    );
+    assert!(SORTED_ARR[1] < SORTED_ARR[2]);
+    assert_eq!(SORTED_ARR[1] + Wrapping(1), Wrapping(0));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [Wrapping<i32>; 500] = core::array::from_fn(|_| Wrapping(rng.gen()));
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_wrapping_i32_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_rgba_by_luma() {
+    const RED: u32 = 0xFF00_00FF;
+    const GREEN: u32 = 0x00FF_00FF;
+    const BLUE: u32 = 0x0000_FFFF;
+    const BLACK: u32 = 0x0000_00FF;
+    const WHITE: u32 = 0xFFFF_FFFF;
+    const TRANSPARENT_RED: u32 = 0xFF00_0000;
+
+    // At equal intensity green is the brightest primary color and blue the darkest.
+    const PRIMARIES: [u32; 3] = into_sorted_rgba_by_luma_array([GREEN, BLUE, RED]);
+    assert_eq!(PRIMARIES, [BLUE, RED, GREEN]);
+
+    // Alpha is ignored, so the two reds tie and keep their order.
+    const MIXED: [u32; 6] =
+        into_sorted_rgba_by_luma_array([WHITE, TRANSPARENT_RED, GREEN, RED, BLACK, BLUE]);
+    assert_eq!(MIXED, [BLACK, BLUE, TRANSPARENT_RED, RED, GREEN, WHITE]);
+    assert_eq!(into_sorted_rgba_by_luma_array([]), [0_u32; 0]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_colors: [u32; 300] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_colors;
+    expected.sort_by_key(|&color| {
+        let [r, g, b, _] = color.to_be_bytes();
+        (77 * u32::from(r) + 150 * u32::from(g) + 29 * u32::from(b)) >> 8
+    });
+    assert_eq!(into_sorted_rgba_by_luma_array(random_colors), expected);
+}
+
+#[test]
+fn test_sort_char_ascii_casefold() {
+    const LETTERS: [char; 4] = into_sorted_char_array_ascii_casefold(['B', 'a', 'A', 'b']);
+    assert_eq!(LETTERS, ['A', 'a', 'B', 'b']);
+
+    // Non-ASCII letters are not case folded.
+    const MIXED: [char; 8] =
+        into_sorted_char_array_ascii_casefold(['ä', 'z', 'Ä', 'Z', '1', 'a', '[', 'a']);
+    assert_eq!(MIXED, ['1', '[', 'a', 'a', 'Z', 'z', 'Ä', 'ä']);
+    assert_eq!(into_sorted_char_array_ascii_casefold([]), [' '; 0]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_chars: [char; 300] = core::array::from_fn(|_| {
+        if rng.gen() {
+            rng.gen_range(' '..='~')
+        } else {
+            rng.gen()
+        }
+    });
+    let mut expected = random_chars;
+    expected.sort_by_key(|&c| (c.to_ascii_lowercase(), c));
+    assert_eq!(
+        into_sorted_char_array_ascii_casefold(random_chars),
+        expected
+    );
+}
+
+#[test]
+fn test_sort_semver_packed() {
+    const SORTED: [u32; 9] = into_sorted_semver_packed_array([
+        pack_semver(2, 0, 0),
+        pack_semver(1, 0, 1023),
+        pack_semver(0, 0, 0),
+        pack_semver(1, 2, 0),
+        pack_semver(1023, 1023, 1023),
+        pack_semver(1, 0, 0),
+        pack_semver(0, 1023, 1023),
+        pack_semver(1, 1, 0),
+        pack_semver(1023, 0, 0),
+    ]);
+    assert_eq!(
+        SORTED,
+        [
+            pack_semver(0, 0, 0),
+            pack_semver(0, 1023, 1023),
+            pack_semver(1, 0, 0),
+            pack_semver(1, 0, 1023),
+            pack_semver(1, 1, 0),
+            pack_semver(1, 2, 0),
+            pack_semver(2, 0, 0),
+            pack_semver(1023, 0, 0),
+            pack_semver(1023, 1023, 1023),
+        ]
+    );
+    assert_eq!(pack_semver(1023, 1023, 1023), (1 << 30) - 1);
+    assert_eq!(into_sorted_semver_packed_array([]), [0_u32; 0]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_versions: [(u16, u16, u16); 300] = core::array::from_fn(|_| {
+        (
+            rng.gen_range(0..4),
+            rng.gen_range(0..4),
+            rng.gen_range(0..1024),
+        )
+    });
+    let mut expected = random_versions;
+    expected.sort();
+    let sorted = into_sorted_semver_packed_array(
+        random_versions.map(|(major, minor, patch)| pack_semver(major, minor, patch)),
+    );
+    assert_eq!(
+        sorted,
+        expected.map(|(major, minor, patch)| pack_semver(major, minor, patch))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_pack_semver_too_large() {
+    pack_semver(1, 1024, 0);
+}
+
+#[test]
+fn test_sort_be_u16_bytes() {
+    const SORTED_ARR: [[u8; 2]; 7] = into_sorted_be_u16_bytes_array([
+        [255, 255],
+        [1, 187],
+        [0, 80],
+        [0, 81],
+        [1, 0],
+        [0, 80],
+        [0, 255],
+    ]);
+    assert_eq!(
+        SORTED_ARR,
+        [
+            [0, 80],
+            [0, 80],
+            [0, 81],
+            [0, 255],
+            [1, 0],
+            [1, 187],
+            [255, 255]
+        ]
+    );
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [[u8; 2]; 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort_by_key(|&bytes| u16::from_be_bytes(bytes));
+    assert_eq!(into_sorted_be_u16_bytes_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_f16_bits() {
+    const NEGATIVE_NAN: u16 = 0xFE00;
+    const NEGATIVE_INFINITY: u16 = 0xFC00;
+    const NEGATIVE_ONE: u16 = 0xBC00;
+    const NEGATIVE_SMALLEST_SUBNORMAL: u16 = 0x8001;
+    const NEGATIVE_ZERO: u16 = 0x8000;
+    const ZERO: u16 = 0x0000;
+    const SMALLEST_SUBNORMAL: u16 = 0x0001;
+    const ONE_HALF: u16 = 0x3800;
+    const ONE: u16 = 0x3C00;
+    const MAX: u16 = 0x7BFF;
+    const INFINITY: u16 = 0x7C00;
+    const NAN: u16 = 0x7E00;
+
+    const SORTED_ARR: [u16; 12] = into_sorted_f16_bits_array([
+        ONE,
+        NAN,
+        NEGATIVE_ZERO,
+        MAX,
+        NEGATIVE_INFINITY,
+        SMALLEST_SUBNORMAL,
+        ZERO,
+        NEGATIVE_NAN,
+        ONE_HALF,
+        NEGATIVE_ONE,
+        INFINITY,
+        NEGATIVE_SMALLEST_SUBNORMAL,
+    ]);
+    assert_eq!(
+        SORTED_ARR,
+        [
+            NEGATIVE_NAN,
+            NEGATIVE_INFINITY,
+            NEGATIVE_ONE,
+            NEGATIVE_SMALLEST_SUBNORMAL,
+            NEGATIVE_ZERO,
+            ZERO,
+            SMALLEST_SUBNORMAL,
+            ONE_HALF,
+            ONE,
+            MAX,
+            INFINITY,
+            NAN,
+        ]
+    );
+
+    // Every f16 converts exactly to an f32, so the total order of the f32s is the expected order.
+    fn f16_bits_to_f32(bits: u16) -> f32 {
+        let sign = u32::from(bits >> 15) << 31;
+        let exponent = u32::from((bits >> 10) & 0x1F);
+        let mantissa = u32::from(bits & 0x3FF);
+        let magnitude = match exponent {
+            0 => mantissa as f32 * 2.0_f32.powi(-24),
+            0x1F => f32::from_bits(0x7F80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(((exponent + 127 - 15) << 23) | (mantissa << 13)),
+        };
+        f32::from_bits(sign | magnitude.to_bits())
+    }
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u16; 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort_by(|&a, &b| f16_bits_to_f32(a).total_cmp(&f16_bits_to_f32(b)));
+    assert_eq!(into_sorted_f16_bits_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_reverse_i32() {
+    use core::cmp::Reverse;
+
+    const SORTED_ARR: [Reverse<i32>; 3] =
+        into_sorted_reverse_i32_array([Reverse(1), Reverse(3), Reverse(2)]);
+    assert_eq!(SORTED_ARR, [Reverse(3), Reverse(2), Reverse(1)]);
+    assert_eq!(into_sorted_reverse_i32_array::<0>([]), []);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [Reverse<i32>; 500] =
+        core::array::from_fn(|_| Reverse(rng.gen_range(-50..50)));
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_reverse_i32_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_q16_16() {
+    use compile_time_sort::Q16_16;
+
+    /// Converts the float to the nearest Q16.16 number.
+    fn q(x: f64) -> Q16_16 {
+        Q16_16((x * 65536.0).round() as i32)
+    }
+
+    const SORTED_ARR: [Q16_16; 7] = into_sorted_q16_16_array([
+        Q16_16(1 << 15),
+        Q16_16::from_int(-1),
+        Q16_16(i32::MIN),
+        Q16_16(0),
+        Q16_16(-1),
+        Q16_16(i32::MAX),
+        Q16_16::from_int(1),
+    ]);
+    assert_eq!(
+        SORTED_ARR,
+        [
+            Q16_16(i32::MIN),
+            Q16_16::from_int(-1),
+            Q16_16(-1),
+            Q16_16(0),
+            Q16_16(1 << 15),
+            Q16_16::from_int(1),
+            Q16_16(i32::MAX),
+        ]
+    );
+
+    assert_eq!(
+        into_sorted_q16_16_array([
+            q(2.25),
+            q(-0.5),
+            q(-32768.0),
+            q(0.0),
+            q(-1.75),
+            q(1.0 / 65536.0)
+        ]),
+        [
+            q(-32768.0),
+            q(-1.75),
+            q(-0.5),
+            q(0.0),
+            q(1.0 / 65536.0),
+            q(2.25)
+        ]
+    );
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_floats: [f64; 500] = core::array::from_fn(|_| rng.gen_range(-32768.0..32767.0));
+    let sorted = into_sorted_q16_16_array(random_floats.map(q));
+    let mut expected = random_floats;
+    expected.sort_by(f64::total_cmp);
+    assert_eq!(sorted, expected.map(q));
+}
+
+#[rustversion::since(1.74.0)]
+#[test]
+fn test_sort_saturating_i32() {
+    use compile_time_sort::into_sorted_saturating_i32_array;
+    use core::num::Saturating;
+
+    const SORTED_ARR: [Saturating<i32>; 3] =
+        into_sorted_saturating_i32_array([Saturating(0), Saturating(i32::MAX), Saturating(-1)]);
+    assert_eq!(
+        SORTED_ARR,
+        [Saturating(-1), Saturating(0), Saturating(i32::MAX)]
+    );
+    assert_eq!(SORTED_ARR[2] + Saturating(1), Saturating(i32::MAX));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [Saturating<i32>; 500] = core::array::from_fn(|_| Saturating(rng.gen()));
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_saturating_i32_array(random_array), expected);
+}
+
+#[test]
+fn test_sort_counting_sort_types_bounded() {
+    use compile_time_sort::{
+        into_sorted_bool_array_bounded, into_sorted_i8_array_bounded, into_sorted_u8_array_bounded,
+        RECOMMENDED_MAX_COUNTING_SORT_LEN,
+    };
+
+    const SORTED_U8: [u8; 1000] =
+        into_sorted_u8_array_bounded::<1000, RECOMMENDED_MAX_COUNTING_SORT_LEN>([7; 1000]);
+    const SORTED_I8: [i8; 4] = into_sorted_i8_array_bounded::<4, 4>([1, -1, 0, i8::MIN]);
+    const SORTED_BOOL: [bool; 3] = into_sorted_bool_array_bounded::<3, 10>([true, false, true]);
+
+    assert_eq!(SORTED_U8, [7; 1000]);
+    assert_eq!(SORTED_I8, [i8::MIN, -1, 0, 1]);
+    assert_eq!(SORTED_BOOL, [false, true, true]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u8; 500] = core::array::from_fn(|_| rng.gen());
+    assert_eq!(
+        into_sorted_u8_array_bounded::<500, 500>(random_array),
+        into_sorted_u8_array(random_array)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_sort_counting_sort_types_bounded_too_long() {
+    compile_time_sort::into_sorted_u8_array_bounded::<5, 4>([0; 5]);
+}
+
+#[test]
+fn test_sort_u8_by_rank() {
+    const REVERSED: [u8; 256] = {
+        let mut rank = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            rank[i] = u8::MAX - i as u8;
+            i += 1;
+        }
+        rank
+    };
+    const SORTED_ARR: [u8; 6] = into_sorted_u8_array_by_rank([3, 0, u8::MAX, 7, 3, 1], &REVERSED);
+    assert_eq!(SORTED_ARR, [u8::MAX, 7, 3, 3, 1, 0]);
+
+    // Rank only by the parity of the value, so that ties show that the sort is stable.
+    const PARITY: [u8; 256] = {
+        let mut rank = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            rank[i] = (i % 2) as u8;
+            i += 1;
+        }
+        rank
+    };
+    const BY_PARITY: [u8; 7] = into_sorted_u8_array_by_rank([5, 4, 1, 8, 0, 3, 2], &PARITY);
+    assert_eq!(BY_PARITY, [4, 8, 0, 2, 5, 1, 3]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u8; 500] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_array;
+    expected.sort_by_key(|&x| core::cmp::Reverse(x));
+    assert_eq!(
+        into_sorted_u8_array_by_rank(random_array, &REVERSED),
+        expected
+    );
+    let mut stable_expected = random_array;
+    stable_expected.sort_by_key(|&x| x % 2);
+    assert_eq!(
+        into_sorted_u8_array_by_rank(random_array, &PARITY),
+        stable_expected
+    );
+}
+
+#[test]
+fn test_sort_nonzero_u8() {
+    use core::num::NonZeroU8;
+
+    const ALL_EQUAL: [NonZeroU8; 50] = into_sorted_nonzero_u8_array([NonZeroU8::MAX; 50]);
+    assert_eq!(ALL_EQUAL, [NonZeroU8::MAX; 50]);
+
+    const FULL_RANGE: [NonZeroU8; 255] = {
+        let mut arr = [NonZeroU8::MIN; 255];
+        let mut i = 0;
+        while i < arr.len() {
+            arr[i] = match NonZeroU8::new(u8::MAX - i as u8) {
+                Some(nz) => nz,
+                None => panic!("the value is never zero"),
+            };
+            i += 1;
+        }
+        into_sorted_nonzero_u8_array(arr)
+    };
+    assert!(FULL_RANGE.is_sorted());
+    assert!(FULL_RANGE
+        .iter()
+        .enumerate()
+        .all(|(i, nz)| nz.get() as usize == i + 1));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [NonZeroU8; 500] =
+        core::array::from_fn(|_| NonZeroU8::new(rng.gen_range(1..=u8::MAX)).unwrap());
+    let mut expected = random_array;
+    expected.sort();
+    assert_eq!(into_sorted_nonzero_u8_array(random_array), expected);
+}
+
+#[test]
+fn test_cdf_u8() {
+    const EMPTY: [usize; 256] = cdf_u8_array([]);
+    assert_eq!(EMPTY, [0; 256]);
+
+    const EXTREMES: [usize; 256] = cdf_u8_array([u8::MAX, 0, u8::MAX]);
+    assert_eq!(EXTREMES[0], 1);
+    assert_eq!(EXTREMES[254], 1);
+    assert_eq!(EXTREMES[255], 3);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u8; 500] = core::array::from_fn(|_| rng.gen());
+    let cdf = cdf_u8_array(random_array);
+    assert!(cdf.is_sorted());
+    assert_eq!(cdf[255], random_array.len());
+    for (value, &count) in cdf.iter().enumerate() {
+        assert_eq!(
+            random_array
+                .iter()
+                .filter(|&&x| x as usize <= value)
+                .count(),
+            count
+        );
+    }
+}
+
+#[test]
+fn test_into_sorted_with_entropy_u8() {
+    const EMPTY: ([u8; 0], u32) = into_sorted_with_entropy_u8_array([]);
+    const ALL_EQUAL: ([u8; 300], u32) = into_sorted_with_entropy_u8_array([u8::MAX; 300]);
+    const UNIFORM: ([u8; 512], u32) = {
+        let mut array = [0; 512];
+        let mut i = 0;
+        while i < 512 {
+            array[i] = (i * 37 % 256) as u8;
+            i += 1;
+        }
+        into_sorted_with_entropy_u8_array(array)
+    };
+    const COIN: ([u8; 6], u32) = into_sorted_with_entropy_u8_array([1, 0, 1, 0, 0, 1]);
+    assert_eq!(EMPTY, ([], 0));
+    assert_eq!(ALL_EQUAL, ([u8::MAX; 300], 0));
+    assert_eq!(UNIFORM.1, 8000);
+    assert!(UNIFORM.0.is_sorted());
+    assert_eq!(COIN, ([0, 0, 0, 1, 1, 1], 1000));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u8; 500] = core::array::from_fn(|_| rng.gen_range(0..20));
+    let expected_entropy: f64 = (0..=255u8)
+        .map(|v| random_array.iter().filter(|&&x| x == v).count() as f64 / 500.0)
+        .filter(|&p| p > 0.0)
+        .map(|p| -p * p.log2())
+        .sum();
+    let (sorted, entropy) = into_sorted_with_entropy_u8_array(random_array);
+    assert_eq!(sorted, into_sorted_u8_array(random_array));
+    assert!((entropy as f64 - expected_entropy * 1000.0).abs() <= 1.0);
+}
+
+#[test]
+fn test_sort_bool_bits_u64() {
+    const SORTED: u64 = sort_bool_bits_u64(0b0110_1001, 8);
+    assert_eq!(SORTED, 0b1111_0000);
+    assert_eq!(sort_bool_bits_u64(0, 0), 0);
+    assert_eq!(sort_bool_bits_u64(u64::MAX, 0), 0);
+    assert_eq!(sort_bool_bits_u64(u64::MAX, 64), u64::MAX);
+    assert_eq!(sort_bool_bits_u64(1, 64), 1 << 63);
+    // Bits at or above `count` are ignored.
+    assert_eq!(sort_bool_bits_u64(0b1111_0001, 4), 0b1000);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    for count in 0..=64u8 {
+        let mask: u64 = rng.gen();
+        let bools: Vec<bool> = (0..count).map(|i| mask & (1 << i) != 0).collect();
+        let mut expected = bools.clone();
+        expected.sort();
+
+        let sorted = sort_bool_bits_u64(mask, count);
+        let sorted_bools: Vec<bool> = (0..count).map(|i| sorted & (1 << i) != 0).collect();
+        assert_eq!(sorted_bools, expected);
+        assert_eq!(
+            sorted.count_ones(),
+            bools.iter().filter(|&&b| b).count() as u32
+        );
+        assert_eq!(sorted.checked_shr(count.into()).unwrap_or(0), 0);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_sort_bool_bits_u64_too_many() {
+    sort_bool_bits_u64(0, 65);
+}
+
+#[test]
+fn test_mode_u8_and_i8() {
+    const EMPTY_U8: Option<u8> = mode_u8_array([]);
+    const TIE_U8: Option<u8> = mode_u8_array([200, 7, 200, 7, 255]);
+    const SINGLE_U8: Option<u8> = mode_u8_array([u8::MAX]);
+    assert_eq!(EMPTY_U8, None);
+    assert_eq!(TIE_U8, Some(7));
+    assert_eq!(SINGLE_U8, Some(u8::MAX));
+
+    const EMPTY_I8: Option<i8> = mode_i8_array([]);
+    const TIE_I8: Option<i8> = mode_i8_array([100, -7, 100, -7, 127]);
+    const MIN_I8: Option<i8> = mode_i8_array([i8::MIN, i8::MAX, i8::MIN]);
+    assert_eq!(EMPTY_I8, None);
+    assert_eq!(TIE_I8, Some(-7));
+    assert_eq!(MIN_I8, Some(i8::MIN));
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_array: [u8; 500] = core::array::from_fn(|_| rng.gen());
+    let counts: Vec<usize> = (0..=255u8)
+        .map(|v| random_array.iter().filter(|&&x| x == v).count())
+        .collect();
+    let max_count = *counts.iter().max().unwrap();
+    let expected = counts.iter().position(|&c| c == max_count).unwrap() as u8;
+    assert_eq!(mode_u8_array(random_array), Some(expected));
+}
+
+#[test]
+fn test_char_sort() {
+    const SORTED_ARR: [char; 4] = into_sorted_char_array(['a', '#', '\n', 'A']);
+
+    assert_eq!(SORTED_ARR, ['\n', '#', 'A', 'a'])
+}
+
+#[test]
+fn test_char_sort_random() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    for _ in 0..20 {
+        // `rng.gen::<char>()` only generates valid Unicode scalar values, including ones above the surrogate range.
+        let random_vec: Vec<char> = (0..rng.gen_range(0..=500)).map(|_| rng.gen()).collect();
+
+        // Sort the elements in buckets of a fixed length, with the last bucket padded by its first element.
+        for chunk in random_vec.chunks(100) {
+            let mut bucket = [chunk[0]; 100];
+            bucket[..chunk.len()].copy_from_slice(chunk);
+            let mut expected = bucket;
+            expected.sort();
+            assert_eq!(into_sorted_char_array(bucket), expected);
+        }
+    }
+
+    let few_values: [char; 500] = core::array::from_fn(|_| char::from(rng.gen_range(b'a'..=b'd')));
+    let mut expected = few_values;
+    expected.sort();
+    assert_eq!(into_sorted_char_array(few_values), expected);
+}
+
+#[test]
+fn test_16_bit_counting_sort() {
+    const EXTREMES: [i16; 5000] = {
+        let mut array = [i16::MAX; 5000];
+        array[2500] = i16::MIN;
+        array[4999] = i16::MIN;
+        array
+    };
+    const SORTED_EXTREMES: [i16; 5000] = into_sorted_i16_array_counting(EXTREMES);
+    assert_eq!(SORTED_EXTREMES[..2], [i16::MIN; 2]);
+    assert_eq!(SORTED_EXTREMES[2..], [i16::MAX; 4998]);
+
+    const ALL_EQUAL: [u16; 5000] = into_sorted_u16_array_counting([7; 5000]);
+    assert_eq!(ALL_EQUAL, [7; 5000]);
+
+    const EMPTY: [u16; 0] = into_sorted_u16_array_counting([]);
+    const SINGLE: [i16; 1] = into_sorted_i16_array_counting([i16::MIN]);
+    const SHORT: [u16; 3] = into_sorted_u16_array_counting([u16::MAX, 0, 1]);
+    assert_eq!(EMPTY, []);
+    assert_eq!(SINGLE, [i16::MIN]);
+    assert_eq!(SHORT, [0, 1, u16::MAX]);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_i16: [i16; 5000] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_i16;
+    expected.sort();
+    assert_eq!(into_sorted_i16_array_counting(random_i16), expected);
+
+    let random_u16: [u16; 5000] = core::array::from_fn(|_| rng.gen());
+    let mut expected = random_u16;
+    expected.sort();
+    assert_eq!(into_sorted_u16_array_counting(random_u16), expected);
+
+    let few_values: [i16; 5000] = core::array::from_fn(|_| rng.gen_range(-3..3));
+    let mut expected = few_values;
+    expected.sort();
+    assert_eq!(into_sorted_i16_array_counting(few_values), expected);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_16_bit_counting_sort_slice() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut random_i16: Vec<i16> = (0..5000).map(|_| rng.gen()).collect();
+    random_i16[0] = i16::MAX;
+    random_i16[1] = i16::MIN;
+    let mut expected = random_i16.clone();
+    expected.sort();
+    sort_i16_slice_counting(&mut random_i16);
+    assert_eq!(random_i16, expected);
+
+    let mut random_u16: Vec<u16> = (0..5000).map(|_| rng.gen()).collect();
+    let mut expected = random_u16.clone();
+    expected.sort();
+    sort_u16_slice_counting(&mut random_u16);
+    assert_eq!(random_u16, expected);
+
+    let mut empty: [u16; 0] = [];
+    sort_u16_slice_counting(&mut empty);
+    let mut short = [u16::MAX, 0, 1];
+    sort_u16_slice_counting(&mut short);
+    assert_eq!(short, [0, 1, u16::MAX]);
+}
+
+#[test]
+fn test_char_sort_around_surrogate_gap() {
+    // There are no `char`s in `'\u{D800}'..='\u{DFFF}'`, so these are adjacent in the sorted order.
+    const AROUND_GAP: [char; 6] = [
+        '\u{E000}',
+        '\u{D7FF}',
+        char::MAX,
+        '\u{E001}',
+        '\0',
+        '\u{D7FE}',
+    ];
+    const EXPECTED: [char; 6] = [
+        '\0',
+        '\u{D7FE}',
+        '\u{D7FF}',
+        '\u{E000}',
+        '\u{E001}',
+        char::MAX,
+    ];
+    const SORTED: [char; 6] = into_sorted_char_array(AROUND_GAP);
+    const SORTED_STABLE: [char; 6] = into_sorted_char_array_stable(AROUND_GAP);
+    const SORTED_SHELL: [char; 6] = into_sorted_char_array_shell(AROUND_GAP);
+    assert_eq!(SORTED, EXPECTED);
+    assert_eq!(SORTED_STABLE, EXPECTED);
+    assert_eq!(SORTED_SHELL, EXPECTED);
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let near_gap: [char; 300] = core::array::from_fn(|_| {
+        if rng.gen() {
+            rng.gen_range('\u{D700}'..='\u{D7FF}')
+        } else {
+            rng.gen_range('\u{E000}'..='\u{E0FF}')
+        }
+    });
+    let mut expected = near_gap;
+    expected.sort();
+    assert_eq!(into_sorted_char_array(near_gap), expected);
+    assert_eq!(into_sorted_char_array_stable(near_gap), expected);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_char_slice_sort_random() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    for _ in 0..20 {
+        let mut random_vec: Vec<char> = (0..rng.gen_range(0..=500)).map(|_| rng.gen()).collect();
+        let mut expected = random_vec.clone();
+        expected.sort();
+        sort_char_slice(&mut random_vec);
+        assert_eq!(random_vec, expected);
+    }
+}
+
+#[test]
+fn test_f32_into_sorted() {
+    const ARR: [f32; 5] = [3.0, 1.0, -0.0, 0.0, f32::MIN];
+    const SORTED_ARR: [f32; 5] = into_sorted_f32_array(ARR);
+
+    const CONSTANT_ARR: [f32; 3] = [1.0, 1.0, 1.0];
+    const SORTED_CONSTANT_ARR: [f32; 3] = into_sorted_f32_array(CONSTANT_ARR);
+
+    const NAN_ARR: [f32; 3] = [f32::NAN, 1.0, -2.0];
+    const SORTED_NAN_ARR: [f32; 3] = into_sorted_f32_array(NAN_ARR);
+
+    const INF_ARR: [f32; 4] = [f32::INFINITY, 1.0, f32::NEG_INFINITY, 0.0];
     const SORTED_INF_ARR: [f32; 4] = into_sorted_f32_array(INF_ARR);
 
     assert!(SORTED_ARR.is_sorted());
@@ -506,17 +4410,66 @@ fn test_f64_sort_slice() {
     assert!(all_same.is_sorted());
 }
 
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_u8_slice_with_counts() {
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let mut counts = [usize::MAX; 256];
+    for len in [0, 1, 5, 16, 17, 100, 1000] {
+        let mut random_vec: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        let mut expected = random_vec.clone();
+        expected.sort();
+        sort_u8_slice_with_counts(&mut random_vec, &mut counts);
+        assert_eq!(random_vec, expected);
+    }
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+fn test_sort_floats_partial_cmp() {
+    const SORTED_F32: [f32; 5] = into_sorted_f32_array_partial_cmp([3.0, -0.0, 0.0, f32::MIN, 1.0]);
+    const SORTED_F64: [f64; 5] = {
+        let mut arr = [3.0, 0.0, -0.0, f64::MIN, 1.0];
+        sort_f64_slice_partial_cmp(&mut arr);
+        arr
+    };
+
+    assert!(SORTED_F32.is_sorted());
+    assert!(SORTED_F64.is_sorted());
+
+    let mut rng = SmallRng::from_seed([0b01010101; 32]);
+    let random_f32: [f32; 500] = core::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+    let random_f64: [f64; 500] = core::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+    assert_eq!(
+        into_sorted_f32_array_partial_cmp(random_f32),
+        into_sorted_f32_array(random_f32)
+    );
+    let mut partial = random_f64;
+    sort_f64_slice_partial_cmp(&mut partial);
+    let mut total = random_f64;
+    sort_f64_slice(&mut total);
+    assert_eq!(partial, total);
+}
+
+#[rustversion::since(1.83.0)]
+#[test]
+#[should_panic]
+fn test_sort_floats_partial_cmp_nan() {
+    let mut arr = [1.0, f64::NAN, 0.0];
+    sort_f64_slice_partial_cmp(&mut arr);
+}
+
 #[rustversion::since(1.83.0)]
 quickcheck! {
     fn quickcheck_f32_slice(vec: Vec<f32>) -> bool {
         let mut vec = vec;
         sort_f32_slice(&mut vec);
-        vec.is_sorted_by(|a, b| matches!(a.total_cmp(&b), std::cmp::Ordering::Less | std::cmp::Ordering::Equal))
+        vec.is_sorted_by(|a, b| matches!(a.total_cmp(b), std::cmp::Ordering::Less | std::cmp::Ordering::Equal))
     }
 
     fn quickcheck_f64_slice(vec: Vec<f64>) -> bool {
         let mut vec = vec;
         sort_f64_slice(&mut vec);
-        vec.is_sorted_by(|a, b| matches!(a.total_cmp(&b), std::cmp::Ordering::Less | std::cmp::Ordering::Equal))
+        vec.is_sorted_by(|a, b| matches!(a.total_cmp(b), std::cmp::Ordering::Less | std::cmp::Ordering::Equal))
     }
 }
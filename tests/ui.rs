@@ -0,0 +1,8 @@
+//! Checks the compiler errors emitted for invalid uses of the crate.
+
+#[cfg(feature = "macros")]
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/sorted_array_unsupported_type.rs");
+}
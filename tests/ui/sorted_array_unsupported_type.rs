@@ -0,0 +1,5 @@
+use compile_time_sort::sorted_array;
+
+fn main() {
+    let _sorted = sorted_array!(String; [String::new()]);
+}